@@ -0,0 +1,190 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::consts::USER_AGENT;
+/// Retrieves the current in-app alerts of the logged-in user, such as emergency notices,
+/// unread messages and pending actions.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the logged-in user.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::alerts::{get_alerts, AlertSeverity};
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let alerts = get_alerts("***".to_string(), 0, "***").await?;
+/// for alert in alerts.iter().filter(|a| a.severity == AlertSeverity::Emergency && !a.dismissed) {
+///     println!("{}", alert.title);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_alerts(
+    cookies: String,
+    user_id: i32,
+    school_id: &str,
+) -> Result<Vec<Alert>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Alerts.svc/GetAlertsForUser",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetAlertsForUserReq { user_id })
+        .send()
+        .await?;
+    let res = req.json::<GetAlertsForUserRes>().await?;
+    let mut alerts: Vec<Alert> = Vec::new();
+    for alert in res.d {
+        let e = Alert {
+            id: alert.id,
+            title: alert.title,
+            message: alert.message,
+            severity: AlertSeverity::from(alert.severity),
+            alert_type: AlertType::from(alert.alert_type),
+            created_at: alert.created_timestamp,
+            link: alert.target_url,
+            dismissed: alert.dismissed,
+        };
+        alerts.push(e);
+    }
+    Ok(alerts)
+}
+/// Dismisses an alert, so it is no longer shown to the logged-in user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `alert_id` - The ID of the alert.
+/// * `school_id` - The ID of the school.
+pub async fn dismiss_alert(
+    cookies: String,
+    alert_id: i32,
+    school_id: &str,
+) -> Result<(), reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Alerts.svc/DismissAlert",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&DismissAlertReq { alert_id })
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAlertsForUserReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAlertsForUserRes {
+    d: Vec<AlertRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct AlertRes {
+    id: i32,
+    title: String,
+    message: Option<String>,
+    severity: i32,
+    #[serde(rename = "type")]
+    alert_type: i32,
+    #[serde(rename = "createdTimestamp")]
+    created_timestamp: String,
+    #[serde(rename = "targetUrl")]
+    target_url: Option<String>,
+    dismissed: bool,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct DismissAlertReq {
+    #[serde(rename = "alertId")]
+    alert_id: i32,
+}
+/// Represents an in-app alert.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Alert {
+    /// The ID of the alert.
+    pub id: i32,
+    /// The title of the alert.
+    pub title: String,
+    /// The message of the alert, if any.
+    pub message: Option<String>,
+    /// How urgent the alert is.
+    pub severity: AlertSeverity,
+    /// What the alert is about.
+    pub alert_type: AlertType,
+    /// The time the alert was created. In ISO 8601 format.
+    pub created_at: String,
+    /// The relative link the alert points to in the Compass web UI, if any.
+    pub link: Option<String>,
+    /// Indicates whether the alert has been dismissed.
+    pub dismissed: bool,
+}
+/// How urgent an [`Alert`] is.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertSeverity {
+    /// Informational.
+    Info,
+    /// Needs attention.
+    Warning,
+    /// An emergency notice from the school.
+    Emergency,
+    /// A severity not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for AlertSeverity {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => AlertSeverity::Info,
+            1 => AlertSeverity::Warning,
+            2 => AlertSeverity::Emergency,
+            other => AlertSeverity::Other(other),
+        }
+    }
+}
+/// What an [`Alert`] is about.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlertType {
+    /// An emergency notice from the school.
+    EmergencyNotice,
+    /// One or more unread messages.
+    UnreadMessage,
+    /// An action waiting on the user, e.g. an event consent.
+    PendingAction,
+    /// A type not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for AlertType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => AlertType::EmergencyNotice,
+            2 => AlertType::UnreadMessage,
+            3 => AlertType::PendingAction,
+            other => AlertType::Other(other),
+        }
+    }
+}
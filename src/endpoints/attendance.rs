@@ -1,5 +1,7 @@
+use chrono::{Datelike, NaiveDate};
 use serde::{Deserialize, Serialize};
 
+use crate::endpoints::reference::academic_groups::AcademicGroup;
 use crate::utils::consts::USER_AGENT;
 #[deprecated(note = "subject to change")]
 pub async fn get_attendance_summary(
@@ -17,7 +19,7 @@ pub async fn get_attendance_summary(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -227,7 +229,7 @@ pub async fn get_half_day_summary_grid_lines(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -288,6 +290,39 @@ pub async fn get_half_day_summary_grid_lines(
     }
     Ok(get_half_day_summary_grid_lines)
 }
+/// Retrieves the half-day attendance summary of a user within an academic period,
+/// e.g. a single term.
+///
+/// Lines whose `date` cannot be read are kept rather than dropped, since they cannot be
+/// placed inside or outside the period.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `period` - The academic period, as returned by
+///   [`get_all_academic_groups`](crate::endpoints::reference::academic_groups::get_all_academic_groups).
+/// * `user_id` - ID of the user.
+/// * `school_id` - The ID of the school.
+pub async fn get_half_day_summary_grid_lines_for_period(
+    cookies: String,
+    period: &AcademicGroup,
+    user_id: i32,
+    school_id: &str,
+) -> Result<Vec<SummaryGridLine>, reqwest::Error> {
+    let mut lines: Vec<SummaryGridLine> = Vec::new();
+    for year in period.start.year()..=period.finish.year() {
+        let res =
+            get_half_day_summary_grid_lines(cookies.clone(), year, user_id, school_id).await?;
+        for line in res {
+            let date = line.date.get(..10).unwrap_or(&line.date);
+            match NaiveDate::parse_from_str(date, "%Y-%m-%d") {
+                Ok(date) if !period.contains(date) => {}
+                _ => lines.push(line),
+            }
+        }
+    }
+    Ok(lines)
+}
 #[derive(Serialize, Deserialize, Debug)]
 struct GetHalfDaySummaryGridLinesReq {
     #[serde(rename = "userId")]
@@ -411,6 +446,466 @@ pub struct SummaryGridLine {
     pub whole_day_absence: bool,
     pub whole_day_export_identifier: String,
 }
+/// Retrieves the reasons that can be given when explaining an absence.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_attendance_note_reasons(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<AttendanceNoteReason>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/AttendanceV2.svc/GetAttendanceNoteReasons",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json("{}")
+        .send()
+        .await?;
+    let res = req.json::<GetAttendanceNoteReasonsRes>().await?;
+    let mut reasons: Vec<AttendanceNoteReason> = Vec::new();
+    for reason in res.d {
+        let v = AttendanceNoteReason {
+            id: reason.id,
+            name: reason.name,
+            code: reason.code,
+            requires_comment: reason.requires_comment,
+        };
+        reasons.push(v);
+    }
+    Ok(reasons)
+}
+/// Creates an attendance note explaining a student's absence, as a parent.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the student the note is for.
+/// * `start` - Start of the absence. In ISO 8601 format.
+/// * `finish` - End of the absence. In ISO 8601 format.
+/// * `reason_id` - ID of the reason, as returned by [`get_attendance_note_reasons`].
+/// * `comment` - Free-text explanation of the absence.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::attendance::create_attendance_note;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let note = create_attendance_note(
+///     "***".to_string(),
+///     0,
+///     "2024-05-08T08:30:00Z".to_string(),
+///     "2024-05-08T15:30:00Z".to_string(),
+///     1,
+///     "Sick with the flu".to_string(),
+///     "***",
+/// )
+/// .await?;
+/// println!("{} {:?}", note.id, note.approval_status);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_attendance_note(
+    cookies: String,
+    user_id: i32,
+    start: String,
+    finish: String,
+    reason_id: i32,
+    comment: String,
+    school_id: &str,
+) -> Result<AttendanceNote, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/AttendanceV2.svc/CreateAttendanceNote",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&CreateAttendanceNoteReq {
+            user_id,
+            start,
+            finish,
+            reason_id,
+            comment,
+        })
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<CreateAttendanceNoteRes>().await?;
+    Ok(AttendanceNote {
+        id: res.d.id,
+        user_id: res.d.user_id,
+        start: res.d.start,
+        finish: res.d.finish,
+        reason_id: res.d.reason_id,
+        comment: res.d.comment,
+        approval_status: AttendanceNoteApprovalStatus::from(res.d.approval_status),
+    })
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAttendanceNoteReasonsRes {
+    d: Vec<AttendanceNoteReasonRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct AttendanceNoteReasonRes {
+    id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    code: String,
+    #[serde(rename = "requiresComment")]
+    requires_comment: bool,
+}
+/// Represents a reason that can be given in an attendance note.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AttendanceNoteReason {
+    /// The ID of the reason.
+    pub id: i32,
+    /// The name of the reason, e.g. `Illness`.
+    pub name: String,
+    /// The code of the reason.
+    pub code: String,
+    /// Indicates whether a comment must be given with the reason.
+    pub requires_comment: bool,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct CreateAttendanceNoteReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    start: String,
+    finish: String,
+    #[serde(rename = "reasonId")]
+    reason_id: i32,
+    comment: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct CreateAttendanceNoteRes {
+    d: AttendanceNoteRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct AttendanceNoteRes {
+    id: i32,
+    #[serde(rename = "userId")]
+    user_id: i32,
+    start: String,
+    finish: String,
+    #[serde(rename = "reasonId")]
+    reason_id: i32,
+    comment: String,
+    #[serde(rename = "approvalStatus")]
+    approval_status: i32,
+}
+/// Represents an attendance note explaining an absence.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AttendanceNote {
+    /// The ID of the attendance note.
+    pub id: i32,
+    /// The ID of the student the note is for.
+    pub user_id: i32,
+    /// Start of the absence. In ISO 8601 format.
+    pub start: String,
+    /// End of the absence. In ISO 8601 format.
+    pub finish: String,
+    /// The ID of the reason for the absence.
+    pub reason_id: i32,
+    /// The explanation of the absence.
+    pub comment: String,
+    /// Whether the school has approved the note.
+    pub approval_status: AttendanceNoteApprovalStatus,
+}
+/// The approval status of an [`AttendanceNote`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttendanceNoteApprovalStatus {
+    /// The note is waiting to be approved by the school.
+    Pending,
+    /// The note has been approved.
+    Approved,
+    /// The note has been rejected.
+    Rejected,
+    /// A status not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for AttendanceNoteApprovalStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => AttendanceNoteApprovalStatus::Pending,
+            1 => AttendanceNoteApprovalStatus::Approved,
+            2 => AttendanceNoteApprovalStatus::Rejected,
+            other => AttendanceNoteApprovalStatus::Other(other),
+        }
+    }
+}
+/// Retrieves the roll of a class instance, with the current status of every student.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `instance_id` - Instance ID of the class, as in
+///   [`Event::instance_id`](crate::endpoints::calendar::events::Event::instance_id).
+/// * `school_id` - The ID of the school.
+pub async fn get_roll(
+    cookies: String,
+    instance_id: &str,
+    school_id: &str,
+) -> Result<Roll, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/AttendanceV2.svc/GetRollForInstance",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetRollForInstanceReq {
+            instance_id: instance_id.to_string(),
+        })
+        .send()
+        .await?;
+    let res = req.json::<RollRes>().await?;
+    Ok(res.d.into())
+}
+/// Submits the status of one or more students on the roll of a class instance, as a
+/// teacher, returning the updated roll.
+///
+/// Students not included in `marks` keep their current status, so marks taken offline
+/// can be synced in batches.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `instance_id` - Instance ID of the class.
+/// * `marks` - The statuses to record.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::attendance::{get_roll, submit_roll, RollMark, RollStatus};
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let roll = get_roll("***".to_string(), "***", "***").await?;
+/// let marks: Vec<RollMark> = roll
+///     .students
+///     .iter()
+///     .map(|student| RollMark { user_id: student.user_id, status: RollStatus::Present })
+///     .collect();
+/// let roll = submit_roll("***".to_string(), "***", &marks, "***").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn submit_roll(
+    cookies: String,
+    instance_id: &str,
+    marks: &[RollMark],
+    school_id: &str,
+) -> Result<Roll, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/AttendanceV2.svc/SaveRollForInstance",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let mut statuses: Vec<RollMarkReq> = Vec::new();
+    for mark in marks {
+        let (status, late_minutes) = mark.status.to_raw();
+        let v = RollMarkReq {
+            user_id: mark.user_id,
+            status,
+            late_minutes,
+        };
+        statuses.push(v);
+    }
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&SaveRollForInstanceReq {
+            instance_id: instance_id.to_string(),
+            statuses,
+        })
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<RollRes>().await?;
+    Ok(res.d.into())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetRollForInstanceReq {
+    #[serde(rename = "instanceId")]
+    instance_id: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SaveRollForInstanceReq {
+    #[serde(rename = "instanceId")]
+    instance_id: String,
+    statuses: Vec<RollMarkReq>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct RollMarkReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    status: i32,
+    #[serde(rename = "lateMinutes")]
+    late_minutes: Option<i32>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct RollRes {
+    d: RollDataRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct RollDataRes {
+    #[serde(rename = "instanceId")]
+    instance_id: String,
+    #[serde(rename = "activityName")]
+    activity_name: String,
+    start: String,
+    finish: String,
+    #[serde(rename = "rollMarked")]
+    roll_marked: bool,
+    students: Vec<RollStudentRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct RollStudentRes {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "ii")]
+    import_id: String,
+    status: i32,
+    #[serde(rename = "lateMinutes")]
+    late_minutes: Option<i32>,
+}
+impl From<RollDataRes> for Roll {
+    fn from(roll: RollDataRes) -> Self {
+        let mut students: Vec<RollStudent> = Vec::new();
+        for student in roll.students {
+            let v = RollStudent {
+                user_id: student.user_id,
+                name: student.name,
+                import_id: student.import_id,
+                status: RollStatus::from_raw(student.status, student.late_minutes),
+            };
+            students.push(v);
+        }
+        Roll {
+            instance_id: roll.instance_id,
+            activity_name: roll.activity_name,
+            start: roll.start,
+            finish: roll.finish,
+            roll_marked: roll.roll_marked,
+            students,
+        }
+    }
+}
+/// Represents the roll of a class instance.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Roll {
+    /// Instance ID of the class.
+    pub instance_id: String,
+    /// Name of the class.
+    pub activity_name: String,
+    /// Start time of the class. In ISO 8601 format.
+    pub start: String,
+    /// Finish time of the class. In ISO 8601 format.
+    pub finish: String,
+    /// Indicates whether the roll has been marked.
+    pub roll_marked: bool,
+    /// The students on the roll.
+    pub students: Vec<RollStudent>,
+}
+/// Represents a student on a roll.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RollStudent {
+    /// The ID of the student.
+    pub user_id: i32,
+    /// The full name of the student.
+    pub name: String,
+    /// The import identifier of the student.
+    pub import_id: String,
+    /// The current status of the student.
+    pub status: RollStatus,
+}
+/// Represents the status to record for a student on a roll.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RollMark {
+    /// The ID of the student.
+    pub user_id: i32,
+    /// The status of the student.
+    pub status: RollStatus,
+}
+/// The status of a student on a roll.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollStatus {
+    /// The roll has not been marked for the student yet.
+    NotMarked,
+    /// The student is present.
+    Present,
+    /// The student arrived late.
+    Late {
+        /// How many minutes late the student arrived.
+        minutes: i32,
+    },
+    /// The student is absent.
+    Absent,
+    /// A status not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl RollStatus {
+    fn from_raw(status: i32, late_minutes: Option<i32>) -> Self {
+        match status {
+            0 => RollStatus::NotMarked,
+            1 => RollStatus::Present,
+            2 => RollStatus::Late {
+                minutes: late_minutes.unwrap_or(0),
+            },
+            3 => RollStatus::Absent,
+            other => RollStatus::Other(other),
+        }
+    }
+    fn to_raw(self) -> (i32, Option<i32>) {
+        match self {
+            RollStatus::NotMarked => (0, None),
+            RollStatus::Present => (1, None),
+            RollStatus::Late { minutes } => (2, Some(minutes)),
+            RollStatus::Absent => (3, None),
+            RollStatus::Other(other) => (other, None),
+        }
+    }
+}
 // pub async fn get_periods_for_timeline(
 //     cookies: String,
 //     date: String,
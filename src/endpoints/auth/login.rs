@@ -15,9 +15,13 @@ use crate::utils::consts::USER_AGENT;
 /// ❌ SAML not supported
 /// # Example
 ///
-/// ```
+/// ```no_run
+/// # use compassapi::endpoints::auth::login::authenticate_user_credentials;
+/// # async fn run() -> Result<(), reqwest::Error> {
 /// let auth = authenticate_user_credentials("JOHNDOE", "***", "***").await?;
 /// println!("{:?}", auth); // Output: AuthenticatedUserCredentials { success: true, user_id: 0, cookies: "***" }
+/// # Ok(())
+/// # }
 /// ```
 pub async fn authenticate_user_credentials(
     username: &str,
@@ -54,6 +58,7 @@ pub async fn authenticate_user_credentials(
         school_id: school_id.to_string(),
     })
 }
+#[allow(dead_code)]
 #[derive(Serialize, Deserialize, Debug)]
 struct AuthenticatedUserCredentialsErr {
     success: bool,
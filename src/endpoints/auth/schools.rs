@@ -0,0 +1,159 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::consts::{SCHOOL_DIRECTORY_URL, USER_AGENT};
+/// Searches for schools by name. Does not require authentication.
+///
+/// # Arguments
+///
+/// * `query` - Part of the name of the school.
+/// * `region` - The region to search in, e.g. `VIC`.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::auth::schools::search_schools;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let schools = search_schools("high", "VIC").await?;
+/// for school in schools {
+///     println!("{} ({})", school.name, school.school_id);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn search_schools(query: &str, region: &str) -> Result<Vec<School>, reqwest::Error> {
+    let url = format!(
+        "{}/services/admin.svc/GetSchoolNameInRegion",
+        SCHOOL_DIRECTORY_URL
+    );
+    let body = GetSchoolNameInRegionReq {
+        search_term: query.to_string(),
+        region: region.to_string(),
+    };
+    let req = reqwest::ClientBuilder::new()
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetSchoolNameInRegionRes>().await?;
+    let mut schools: Vec<School> = Vec::new();
+    for school in res.d {
+        let e = School {
+            name: school.name,
+            region: school.region,
+            school_id: school_id_from_fqdn(&school.fqdn),
+            fqdn: school.fqdn,
+        };
+        schools.push(e);
+    }
+    Ok(schools)
+}
+/// Retrieves basic details of a school, including the login methods it supports.
+/// Does not require authentication.
+///
+/// # Arguments
+///
+/// * `school_id` - The ID of the school.
+pub async fn get_school_detail_basic(school_id: &str) -> Result<SchoolDetails, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/services/admin.svc/getschooldetailbasic",
+        school_id
+    );
+    let req = reqwest::ClientBuilder::new()
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json("{}")
+        .send()
+        .await?;
+    let res = req.json::<GetSchoolDetailBasicRes>().await?;
+    let mut login_methods: Vec<LoginMethod> = Vec::new();
+    if res.d.password_login_enabled {
+        login_methods.push(LoginMethod::Password);
+    }
+    if res.d.saml_enabled {
+        login_methods.push(LoginMethod::Saml);
+    }
+    Ok(SchoolDetails {
+        name: res.d.name,
+        region: res.d.region,
+        school_id: school_id.to_string(),
+        fqdn: res.d.fqdn,
+        login_methods,
+        saml_login_url: res.d.saml_url,
+    })
+}
+fn school_id_from_fqdn(fqdn: &str) -> String {
+    fqdn.split('.').next().unwrap_or(fqdn).to_string()
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetSchoolNameInRegionReq {
+    #[serde(rename = "searchTerm")]
+    search_term: String,
+    region: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetSchoolNameInRegionRes {
+    d: Vec<SchoolRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SchoolRes {
+    #[serde(rename = "n")]
+    name: String,
+    region: String,
+    fqdn: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetSchoolDetailBasicRes {
+    d: SchoolDetailBasicRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SchoolDetailBasicRes {
+    #[serde(rename = "n")]
+    name: String,
+    region: String,
+    fqdn: String,
+    #[serde(rename = "passwordLoginEnabled")]
+    password_login_enabled: bool,
+    #[serde(rename = "samlEnabled")]
+    saml_enabled: bool,
+    #[serde(rename = "samlUrl")]
+    saml_url: Option<String>,
+}
+/// Represents a school returned by a school search.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct School {
+    /// The name of the school.
+    pub name: String,
+    /// The region of the school.
+    pub region: String,
+    /// The ID of the school, i.e. its `compass.education` subdomain.
+    /// This is the `school_id` expected by every other endpoint.
+    pub school_id: String,
+    /// The fully qualified domain name of the school's Compass tenant.
+    pub fqdn: String,
+}
+/// Represents the basic details of a school.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SchoolDetails {
+    /// The name of the school.
+    pub name: String,
+    /// The region of the school.
+    pub region: String,
+    /// The ID of the school, i.e. its `compass.education` subdomain.
+    pub school_id: String,
+    /// The fully qualified domain name of the school's Compass tenant.
+    pub fqdn: String,
+    /// The login methods supported by the school.
+    pub login_methods: Vec<LoginMethod>,
+    /// The URL to start a SAML login at, if SAML is supported.
+    pub saml_login_url: Option<String>,
+}
+/// A login method supported by a school.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LoginMethod {
+    /// Username and password, as used by [`authenticate_user_credentials`](super::login::authenticate_user_credentials).
+    Password,
+    /// SAML single sign-on.
+    Saml,
+}
@@ -13,8 +13,12 @@ use crate::utils::consts::USER_AGENT;
 /// * `school_id` - The ID of the school.
 /// # Example
 ///
-/// ```
+/// ```no_run
+/// # use compassapi::endpoints::calendar::events::get_calendar_events_by_user;
+/// # async fn run() -> Result<(), reqwest::Error> {
 /// let events = get_calendar_events_by_user("***".to_string(), 0, "2024-05-08".to_string(), "2024-05-08".to_string(), "***").await?;
+/// # Ok(())
+/// # }
 /// ```
 pub async fn get_calendar_events_by_user(
     cookies: String,
@@ -31,10 +35,10 @@ pub async fn get_calendar_events_by_user(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let body = GetCalendarEventsByUserRequest {
-        user_id: user_id,
+        user_id,
         start_date: start,
         end_date: end,
         limit: 50,
@@ -113,8 +117,8 @@ pub async fn get_calendar_events_by_user(
             text_color: event.text_color,
             title: event.title,
             unavailable_pd: event.unavailable_pd,
-            locations: locations,
-            managers: managers,
+            locations,
+            managers,
         };
         events.push(e)
     }
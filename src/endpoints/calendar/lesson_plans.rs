@@ -0,0 +1,141 @@
+use serde::{Deserialize, Serialize};
+
+use super::events::Event;
+use crate::endpoints::news::{AssetRes, Attachment};
+use crate::utils::consts::USER_AGENT;
+use crate::utils::html::html_to_text;
+
+/// Retrieves the lesson plan of a class instance.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `instance_id` - Instance ID of the class, as in [`Event::instance_id`].
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::calendar::lesson_plans::get_lesson_plan;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let plan = get_lesson_plan("***".to_string(), "***", "***").await?;
+/// println!("{}", plan.text);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_lesson_plan(
+    cookies: String,
+    instance_id: &str,
+    school_id: &str,
+) -> Result<LessonPlan, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Activity.svc/GetLessonPlanByInstanceId",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetLessonPlanRequest {
+        instance_id: instance_id.to_string(),
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetLessonPlanResponse>().await?;
+    let mut resources: Vec<LessonPlanResource> = Vec::new();
+    for resource in res.d.resources.unwrap_or_default() {
+        let t = LessonPlanResource {
+            name: resource.name,
+            url: resource.url,
+        };
+        resources.push(t)
+    }
+    let mut attachments: Vec<Attachment> = Vec::new();
+    for attachment in res.d.attachments.unwrap_or_default() {
+        attachments.push(attachment.into());
+    }
+    let html = res.d.content.unwrap_or_default();
+    Ok(LessonPlan {
+        instance_id: instance_id.to_string(),
+        text: html_to_text(&html),
+        html,
+        last_modified: res.d.last_modified,
+        resources,
+        attachments,
+    })
+}
+/// Retrieves the lesson plans of every event that has one configured, e.g. to export
+/// the plans of a unit.
+///
+/// Events without a lesson plan are skipped. Plans are returned in the order of `events`.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `events` - Events as returned by [`get_calendar_events_by_user`](super::events::get_calendar_events_by_user).
+/// * `school_id` - The ID of the school.
+pub async fn get_lesson_plans_for_events(
+    cookies: String,
+    events: &[Event],
+    school_id: &str,
+) -> Result<Vec<LessonPlan>, reqwest::Error> {
+    let mut plans: Vec<LessonPlan> = Vec::new();
+    for event in events.iter().filter(|event| event.lesson_plan_configured) {
+        plans.push(get_lesson_plan(cookies.clone(), &event.instance_id, school_id).await?);
+    }
+    Ok(plans)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetLessonPlanRequest {
+    #[serde(rename = "instanceId")]
+    instance_id: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetLessonPlanResponse {
+    d: LessonPlanRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LessonPlanRes {
+    content: Option<String>,
+    #[serde(rename = "lastModified")]
+    last_modified: Option<String>,
+    resources: Option<Vec<LessonPlanResourceRes>>,
+    attachments: Option<Vec<AssetRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LessonPlanResourceRes {
+    name: String,
+    url: String,
+}
+/// Represents the lesson plan of a class instance.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LessonPlan {
+    /// Instance ID of the class the lesson plan belongs to.
+    pub instance_id: String,
+    /// Content of the lesson plan, as HTML.
+    pub html: String,
+    /// Content of the lesson plan, rendered as plain text.
+    pub text: String,
+    /// Optional time the lesson plan was last modified. In ISO 8601 format.
+    pub last_modified: Option<String>,
+    /// Resources (links) linked from the lesson plan.
+    pub resources: Vec<LessonPlanResource>,
+    /// Files attached to the lesson plan.
+    pub attachments: Vec<Attachment>,
+}
+/// Represents a resource linked from a lesson plan.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LessonPlanResource {
+    /// Name of the resource.
+    pub name: String,
+    /// URL of the resource.
+    pub url: String,
+}
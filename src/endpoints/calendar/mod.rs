@@ -1 +1,2 @@
-pub mod events;
\ No newline at end of file
+pub mod events;
+pub mod lesson_plans;
\ No newline at end of file
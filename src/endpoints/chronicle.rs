@@ -0,0 +1,584 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::news::{AssetRes, Attachment};
+use crate::utils::consts::USER_AGENT;
+use crate::utils::upload::{UploadError, UploadFile};
+
+/// Retrieves a single page of a user's chronicle feed.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `target_user_id` - ID of the user whose chronicle entries are to be retrieved.
+/// * `start` - Start date for the chronicle entries (in a string format).
+/// * `end` - End date for the chronicle entries (in a string format).
+/// * `page` - The page to retrieve, starting at 1.
+/// * `limit` - The maximum number of feed rows per page, at least 1.
+/// * `school_id` - The ID of the school.
+pub async fn get_user_chronicle_feed(
+    cookies: String,
+    target_user_id: i32,
+    start: String,
+    end: String,
+    page: i32,
+    limit: i32,
+    school_id: &str,
+) -> Result<ChronicleFeedPage, ChronicleError> {
+    if page < 1 || limit < 1 {
+        return Err(ChronicleError::InvalidPage { page, limit });
+    }
+    let url = format!(
+        "https://{}.compass.education/Services/ChronicleV2.svc/GetUserChronicleFeed",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetUserChronicleFeedRequest {
+        target_user_id,
+        start_date: start,
+        end_date: end,
+        page,
+        start: (page - 1) * limit,
+        limit,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetUserChronicleFeedResponse>().await?;
+    let rows = res.d.data.len() as i32;
+    let mut entries: Vec<ChronicleEntry> = Vec::new();
+    for row in res.d.data {
+        for entry in row.chronicle_entries {
+            entries.push(entry.into());
+        }
+    }
+    Ok(ChronicleFeedPage {
+        entries,
+        rows,
+        total: res.d.total,
+    })
+}
+/// Retrieves every chronicle entry of a user within a given time range, requesting
+/// pages until the feed is exhausted.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `target_user_id` - ID of the user whose chronicle entries are to be retrieved.
+/// * `start` - Start date for the chronicle entries (in a string format).
+/// * `end` - End date for the chronicle entries (in a string format).
+/// * `school_id` - The ID of the school.
+pub async fn get_all_user_chronicle_entries(
+    cookies: String,
+    target_user_id: i32,
+    start: String,
+    end: String,
+    school_id: &str,
+) -> Result<Vec<ChronicleEntry>, ChronicleError> {
+    let limit = 25;
+    let mut page = 1;
+    let mut rows = 0;
+    let mut entries: Vec<ChronicleEntry> = Vec::new();
+    loop {
+        let res = get_user_chronicle_feed(
+            cookies.clone(),
+            target_user_id,
+            start.clone(),
+            end.clone(),
+            page,
+            limit,
+            school_id,
+        )
+        .await?;
+        rows += res.rows;
+        entries.extend(res.entries);
+        if res.rows == 0 || rows >= res.total {
+            break;
+        }
+        page += 1;
+    }
+    Ok(entries)
+}
+/// Retrieves the chronicle templates the logged-in user can create entries from, with
+/// their field definitions.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_chronicle_templates(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<ChronicleTemplate>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ChronicleV2.svc/GetTemplates",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json("{}")
+        .send()
+        .await?;
+    let res = req.json::<GetTemplatesResponse>().await?;
+    let mut templates: Vec<ChronicleTemplate> = Vec::new();
+    for template in res.d {
+        let mut fields: Vec<ChronicleTemplateField> = Vec::new();
+        for field in template.input_fields {
+            let e = ChronicleTemplateField {
+                id: field.id,
+                name: field.name,
+                description: field.description,
+                field_type: field.field_type,
+                required: field.required,
+                options: field.options.unwrap_or_default(),
+            };
+            fields.push(e);
+        }
+        let e = ChronicleTemplate {
+            id: template.id,
+            name: template.name,
+            category_id: template.category_id,
+            category_name: template.category_name,
+            visible_to_parents: template.show_parent,
+            visible_to_student: template.show_student,
+            fields,
+        };
+        templates.push(e);
+    }
+    Ok(templates)
+}
+/// Creates a chronicle entry for one or more students, as staff. Returns the ID of the
+/// created entry.
+///
+/// Attachments are uploaded in the same request, after being checked against
+/// [`MAX_UPLOAD_FILE_SIZE`](crate::utils::upload::MAX_UPLOAD_FILE_SIZE).
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `entry` - The entry to create.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::chronicle::{create_chronicle_entry, ChronicleError, ChronicleFieldValue, NewChronicleEntry};
+/// # use compassapi::utils::upload::UploadFile;
+/// # async fn run() -> Result<(), ChronicleError> {
+/// let entry = NewChronicleEntry {
+///     template_id: 12,
+///     student_ids: vec![4225],
+///     occurred_at: "2024-05-08T10:30:00Z".to_string(),
+///     field_values: vec![ChronicleFieldValue { field_id: 1, value: "Settled well after recess".to_string() }],
+///     visible_to_parents: false,
+///     visible_to_student: false,
+///     attachments: vec![UploadFile::from_path("incident.jpg").await?],
+/// };
+/// let id = create_chronicle_entry("***".to_string(), entry, "***").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_chronicle_entry(
+    cookies: String,
+    entry: NewChronicleEntry,
+    school_id: &str,
+) -> Result<i32, ChronicleError> {
+    for attachment in &entry.attachments {
+        attachment.checked_size()?;
+    }
+    let url = format!(
+        "https://{}.compass.education/Services/ChronicleV2.svc/SaveChronicleEntry",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let mut input_fields: Vec<ChronicleFieldValueReq> = Vec::new();
+    for value in entry.field_values {
+        let e = ChronicleFieldValueReq {
+            id: value.field_id,
+            value: value.value,
+        };
+        input_fields.push(e);
+    }
+    let body = SaveChronicleEntryRequest {
+        template_id: entry.template_id,
+        student_ids: entry.student_ids,
+        occurred_timestamp: entry.occurred_at,
+        input_fields,
+        show_parent: entry.visible_to_parents,
+        show_student: entry.visible_to_student,
+    };
+    let entry_part =
+        reqwest::multipart::Part::bytes(serde_json::to_vec(&body)?).mime_str("application/json")?;
+    let mut form = reqwest::multipart::Form::new().part("entry", entry_part);
+    for attachment in entry.attachments {
+        let mut part =
+            reqwest::multipart::Part::bytes(attachment.content).file_name(attachment.file_name);
+        if let Some(mime_type) = attachment.mime_type {
+            part = part.mime_str(&mime_type)?;
+        }
+        form = form.part("file", part);
+    }
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .multipart(form)
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<SaveChronicleEntryResponse>().await?;
+    Ok(res.d)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUserChronicleFeedRequest {
+    #[serde(rename = "targetUserId")]
+    target_user_id: i32,
+    #[serde(rename = "startDate")]
+    start_date: String,
+    #[serde(rename = "endDate")]
+    end_date: String,
+    page: i32,
+    start: i32,
+    limit: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUserChronicleFeedResponse {
+    d: ChronicleFeedRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleFeedRes {
+    data: Vec<ChronicleFeedRow>,
+    total: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleFeedRow {
+    #[serde(rename = "chronicleEntries")]
+    chronicle_entries: Vec<ChronicleEntryRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleEntryRes {
+    id: i32,
+    #[serde(rename = "templateId")]
+    template_id: i32,
+    #[serde(rename = "templateName")]
+    template_name: String,
+    #[serde(rename = "categoryId")]
+    category_id: Option<i32>,
+    #[serde(rename = "categoryName")]
+    category_name: Option<String>,
+    #[serde(rename = "createdTimestamp")]
+    created_timestamp: String,
+    #[serde(rename = "occurredTimestamp")]
+    occurred_timestamp: String,
+    #[serde(rename = "userIdCreator")]
+    user_id_creator: i32,
+    #[serde(rename = "userNameCreator")]
+    user_name_creator: Option<String>,
+    #[serde(rename = "userIdSubject")]
+    user_id_subject: i32,
+    #[serde(rename = "showParent")]
+    show_parent: bool,
+    #[serde(rename = "showStudent")]
+    show_student: bool,
+    #[serde(rename = "inputFields")]
+    input_fields: Vec<ChronicleInputFieldRes>,
+    attachments: Option<Vec<AssetRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleInputFieldRes {
+    id: i32,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    field_type: i32,
+    value: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetTemplatesResponse {
+    d: Vec<ChronicleTemplateRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleTemplateRes {
+    id: i32,
+    name: String,
+    #[serde(rename = "categoryId")]
+    category_id: Option<i32>,
+    #[serde(rename = "categoryName")]
+    category_name: Option<String>,
+    #[serde(rename = "showParent")]
+    show_parent: bool,
+    #[serde(rename = "showStudent")]
+    show_student: bool,
+    #[serde(rename = "inputFields")]
+    input_fields: Vec<ChronicleTemplateFieldRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleTemplateFieldRes {
+    id: i32,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    field_type: i32,
+    required: bool,
+    options: Option<Vec<String>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SaveChronicleEntryRequest {
+    #[serde(rename = "templateId")]
+    template_id: i32,
+    #[serde(rename = "studentIds")]
+    student_ids: Vec<i32>,
+    #[serde(rename = "occurredTimestamp")]
+    occurred_timestamp: String,
+    #[serde(rename = "inputFields")]
+    input_fields: Vec<ChronicleFieldValueReq>,
+    #[serde(rename = "showParent")]
+    show_parent: bool,
+    #[serde(rename = "showStudent")]
+    show_student: bool,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ChronicleFieldValueReq {
+    id: i32,
+    value: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SaveChronicleEntryResponse {
+    d: i32,
+}
+impl From<ChronicleEntryRes> for ChronicleEntry {
+    fn from(entry: ChronicleEntryRes) -> Self {
+        let mut fields: Vec<ChronicleField> = Vec::new();
+        for field in entry.input_fields {
+            let e = ChronicleField {
+                id: field.id,
+                name: field.name,
+                description: field.description,
+                field_type: field.field_type,
+                value: field.value,
+            };
+            fields.push(e);
+        }
+        let mut attachments: Vec<Attachment> = Vec::new();
+        for attachment in entry.attachments.unwrap_or_default() {
+            attachments.push(attachment.into());
+        }
+        ChronicleEntry {
+            id: entry.id,
+            template_id: entry.template_id,
+            template_name: entry.template_name,
+            category_id: entry.category_id,
+            category_name: entry.category_name,
+            created_at: entry.created_timestamp,
+            occurred_at: entry.occurred_timestamp,
+            author_id: entry.user_id_creator,
+            author_name: entry.user_name_creator,
+            student_id: entry.user_id_subject,
+            visible_to_parents: entry.show_parent,
+            visible_to_student: entry.show_student,
+            fields,
+            attachments,
+        }
+    }
+}
+/// Represents a page of a user's chronicle feed.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChronicleFeedPage {
+    /// The chronicle entries on this page.
+    pub entries: Vec<ChronicleEntry>,
+    /// The number of feed rows on this page. A row groups one or more entries, and
+    /// `limit` and `total` count rows rather than entries.
+    pub rows: i32,
+    /// The total number of feed rows across all pages.
+    pub total: i32,
+}
+/// Represents a chronicle entry, such as a wellbeing or behaviour record.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChronicleEntry {
+    /// The ID of the chronicle entry.
+    pub id: i32,
+    /// The ID of the template the entry was created from.
+    pub template_id: i32,
+    /// The name of the template the entry was created from.
+    pub template_name: String,
+    /// The ID of the category of the template, if any.
+    pub category_id: Option<i32>,
+    /// The name of the category of the template, if any.
+    pub category_name: Option<String>,
+    /// The time the entry was created. In ISO 8601 format.
+    pub created_at: String,
+    /// The time the recorded event occurred. In ISO 8601 format.
+    pub occurred_at: String,
+    /// The ID of the user who created the entry.
+    pub author_id: i32,
+    /// The name of the user who created the entry.
+    pub author_name: Option<String>,
+    /// The ID of the student the entry is about.
+    pub student_id: i32,
+    /// Indicates whether the entry is visible to parents.
+    pub visible_to_parents: bool,
+    /// Indicates whether the entry is visible to the student.
+    pub visible_to_student: bool,
+    /// The values of the template fields.
+    pub fields: Vec<ChronicleField>,
+    /// The list of attachments associated with the entry.
+    pub attachments: Vec<Attachment>,
+}
+/// Represents the value of a template field within a chronicle entry.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChronicleField {
+    /// The ID of the field.
+    pub id: i32,
+    /// The name of the field.
+    pub name: String,
+    /// The description of the field.
+    pub description: Option<String>,
+    /// The type of the field.
+    pub field_type: i32,
+    /// The value entered for the field, if any.
+    pub value: Option<String>,
+}
+/// Represents a template chronicle entries are created from.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChronicleTemplate {
+    /// The ID of the template.
+    pub id: i32,
+    /// The name of the template.
+    pub name: String,
+    /// The ID of the category of the template, if any.
+    pub category_id: Option<i32>,
+    /// The name of the category of the template, if any.
+    pub category_name: Option<String>,
+    /// Whether entries from this template are visible to parents by default.
+    pub visible_to_parents: bool,
+    /// Whether entries from this template are visible to the student by default.
+    pub visible_to_student: bool,
+    /// The fields of the template.
+    pub fields: Vec<ChronicleTemplateField>,
+}
+/// Represents the definition of a field of a chronicle template.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ChronicleTemplateField {
+    /// The ID of the field.
+    pub id: i32,
+    /// The name of the field.
+    pub name: String,
+    /// The description of the field.
+    pub description: Option<String>,
+    /// The type of the field.
+    pub field_type: i32,
+    /// Indicates whether a value must be given for the field.
+    pub required: bool,
+    /// The allowed values of the field, for fields with a fixed set of options.
+    pub options: Vec<String>,
+}
+/// Represents a chronicle entry to be created.
+#[derive(Debug, Clone)]
+pub struct NewChronicleEntry {
+    /// The ID of the template to create the entry from.
+    pub template_id: i32,
+    /// The IDs of the students the entry is about.
+    pub student_ids: Vec<i32>,
+    /// The time the recorded event occurred. In ISO 8601 format.
+    pub occurred_at: String,
+    /// The values of the template fields.
+    pub field_values: Vec<ChronicleFieldValue>,
+    /// Whether the entry is visible to parents.
+    pub visible_to_parents: bool,
+    /// Whether the entry is visible to the students.
+    pub visible_to_student: bool,
+    /// Files to attach to the entry, e.g. read with [`UploadFile::from_path`].
+    pub attachments: Vec<UploadFile>,
+}
+/// Represents the value of a template field for a new chronicle entry.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ChronicleFieldValue {
+    /// The ID of the field, matching [`ChronicleTemplateField::id`].
+    pub field_id: i32,
+    /// The value of the field.
+    pub value: String,
+}
+/// An error that occurred while retrieving or creating chronicle entries.
+#[derive(Debug)]
+pub enum ChronicleError {
+    /// The requested page or page size is below 1.
+    InvalidPage {
+        /// The requested page.
+        page: i32,
+        /// The requested page size.
+        limit: i32,
+    },
+    /// An attachment could not be read or is not accepted for upload.
+    File(UploadError),
+    /// The entry could not be encoded as JSON.
+    Json(serde_json::Error),
+    /// The request failed or the server rejected it.
+    Request(reqwest::Error),
+}
+impl fmt::Display for ChronicleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChronicleError::InvalidPage { page, limit } => write!(
+                f,
+                "invalid page {} of size {}, both must be at least 1",
+                page, limit
+            ),
+            ChronicleError::File(e) => write!(f, "{}", e),
+            ChronicleError::Json(e) => write!(f, "could not encode entry: {}", e),
+            ChronicleError::Request(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+impl std::error::Error for ChronicleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ChronicleError::File(e) => Some(e),
+            ChronicleError::Json(e) => Some(e),
+            ChronicleError::Request(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<reqwest::Error> for ChronicleError {
+    fn from(e: reqwest::Error) -> Self {
+        ChronicleError::Request(e)
+    }
+}
+impl From<serde_json::Error> for ChronicleError {
+    fn from(e: serde_json::Error) -> Self {
+        ChronicleError::Json(e)
+    }
+}
+impl From<UploadError> for ChronicleError {
+    fn from(e: UploadError) -> Self {
+        ChronicleError::File(e)
+    }
+}
@@ -0,0 +1,427 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::locations::Location;
+use crate::utils::consts::USER_AGENT;
+
+/// Retrieves the parent-teacher conference sessions open to the logged-in user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_conference_sessions(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<ConferenceSession>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/GetConferenceSessions",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json("{}")
+        .send()
+        .await?;
+    let res = req.json::<GetConferenceSessionsRes>().await?;
+    let mut sessions: Vec<ConferenceSession> = Vec::new();
+    for session in res.d {
+        let e = ConferenceSession {
+            id: session.id,
+            name: session.name,
+            start: session.start,
+            finish: session.finish,
+            bookings_open: session.bookings_open,
+            bookings_close: session.bookings_close,
+        };
+        sessions.push(e);
+    }
+    Ok(sessions)
+}
+/// Retrieves the teachers a student can book with in a conference session.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `session_id` - ID of the conference session.
+/// * `student_id` - ID of the student the conference is about.
+/// * `school_id` - The ID of the school.
+pub async fn get_conference_teachers(
+    cookies: String,
+    session_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<Vec<ConferenceTeacher>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/GetTeachersForStudent",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = ConferenceStudentReq {
+        session_id,
+        student_id,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetTeachersForStudentRes>().await?;
+    let mut teachers: Vec<ConferenceTeacher> = Vec::new();
+    for teacher in res.d {
+        let e = ConferenceTeacher {
+            user_id: teacher.user_id,
+            name: teacher.name,
+            import_id: teacher.import_id,
+            activity_names: teacher.activity_names,
+        };
+        teachers.push(e);
+    }
+    Ok(teachers)
+}
+/// Retrieves the time slots of a teacher that are still free in a conference session.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `session_id` - ID of the conference session.
+/// * `teacher_id` - ID of the teacher.
+/// * `student_id` - ID of the student the conference is about.
+/// * `school_id` - The ID of the school.
+pub async fn get_available_time_slots(
+    cookies: String,
+    session_id: i32,
+    teacher_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<Vec<ConferenceSlot>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/GetAvailableTimeSlots",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetAvailableTimeSlotsReq {
+        session_id,
+        teacher_id,
+        student_id,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetConferenceSlotsRes>().await?;
+    let mut slots: Vec<ConferenceSlot> = Vec::new();
+    for slot in res.d {
+        slots.push(slot.into());
+    }
+    Ok(slots)
+}
+/// Books a time slot for a student, returning the booked slot.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `slot_id` - ID of the time slot to book.
+/// * `student_id` - ID of the student the conference is about.
+/// * `school_id` - The ID of the school.
+pub async fn book_time_slot(
+    cookies: String,
+    slot_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<ConferenceSlot, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/BookTimeSlot",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = ConferenceBookingReq {
+        slot_id,
+        student_id,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<BookTimeSlotRes>().await?;
+    Ok(res.d.into())
+}
+/// Cancels the booking of a time slot for a student.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `slot_id` - ID of the booked time slot.
+/// * `student_id` - ID of the student the conference is about.
+/// * `school_id` - The ID of the school.
+pub async fn cancel_booking(
+    cookies: String,
+    slot_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<(), reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/CancelBooking",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = ConferenceBookingReq {
+        slot_id,
+        student_id,
+    };
+    reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+/// Retrieves the confirmed bookings of the logged-in user's family in a conference session.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `session_id` - ID of the conference session.
+/// * `school_id` - The ID of the school.
+pub async fn get_family_bookings(
+    cookies: String,
+    session_id: i32,
+    school_id: &str,
+) -> Result<Vec<ConferenceSlot>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Conferences.svc/GetBookingsForFamily",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetBookingsForFamilyReq { session_id })
+        .send()
+        .await?;
+    let res = req.json::<GetConferenceSlotsRes>().await?;
+    let mut slots: Vec<ConferenceSlot> = Vec::new();
+    for slot in res.d {
+        slots.push(slot.into());
+    }
+    slots.sort_by(|a, b| a.start.cmp(&b.start));
+    Ok(slots)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetConferenceSessionsRes {
+    d: Vec<ConferenceSessionRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConferenceSessionRes {
+    id: i32,
+    name: String,
+    start: String,
+    finish: String,
+    #[serde(rename = "bookingsOpen")]
+    bookings_open: String,
+    #[serde(rename = "bookingsClose")]
+    bookings_close: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConferenceStudentReq {
+    #[serde(rename = "sessionId")]
+    session_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetTeachersForStudentRes {
+    d: Vec<ConferenceTeacherRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConferenceTeacherRes {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "ii")]
+    import_id: String,
+    #[serde(rename = "activityNames")]
+    activity_names: Vec<String>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAvailableTimeSlotsReq {
+    #[serde(rename = "sessionId")]
+    session_id: i32,
+    #[serde(rename = "teacherId")]
+    teacher_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConferenceBookingReq {
+    #[serde(rename = "slotId")]
+    slot_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetBookingsForFamilyReq {
+    #[serde(rename = "sessionId")]
+    session_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetConferenceSlotsRes {
+    d: Vec<ConferenceSlotRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct BookTimeSlotRes {
+    d: ConferenceSlotRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConferenceSlotRes {
+    id: i32,
+    #[serde(rename = "sessionId")]
+    session_id: i32,
+    #[serde(rename = "teacherId")]
+    teacher_id: i32,
+    #[serde(rename = "teacherName")]
+    teacher_name: String,
+    #[serde(rename = "studentId")]
+    student_id: Option<i32>,
+    start: String,
+    finish: String,
+    #[serde(rename = "locationId")]
+    location_id: Option<i32>,
+    #[serde(rename = "locationName")]
+    location_name: Option<String>,
+    booked: bool,
+}
+impl From<ConferenceSlotRes> for ConferenceSlot {
+    fn from(slot: ConferenceSlotRes) -> Self {
+        ConferenceSlot {
+            id: slot.id,
+            session_id: slot.session_id,
+            teacher_id: slot.teacher_id,
+            teacher_name: slot.teacher_name,
+            student_id: slot.student_id,
+            start: slot.start,
+            finish: slot.finish,
+            location_id: slot.location_id,
+            location_name: slot.location_name,
+            booked: slot.booked,
+        }
+    }
+}
+/// Represents a parent-teacher conference session, e.g. `Term 2 Parent-Teacher Interviews`.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConferenceSession {
+    /// The ID of the conference session.
+    pub id: i32,
+    /// The name of the conference session.
+    pub name: String,
+    /// The start time of the conference session. In ISO 8601 format.
+    pub start: String,
+    /// The finish time of the conference session. In ISO 8601 format.
+    pub finish: String,
+    /// The time bookings open. In ISO 8601 format.
+    pub bookings_open: String,
+    /// The time bookings close. In ISO 8601 format.
+    pub bookings_close: String,
+}
+/// Represents a teacher that can be booked for a student.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConferenceTeacher {
+    /// The ID of the teacher.
+    pub user_id: i32,
+    /// The full name of the teacher.
+    pub name: String,
+    /// The import identifier of the teacher.
+    pub import_id: String,
+    /// The names of the student's classes taught by the teacher.
+    pub activity_names: Vec<String>,
+}
+/// Represents a conference time slot.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConferenceSlot {
+    /// The ID of the time slot.
+    pub id: i32,
+    /// The ID of the conference session the time slot belongs to.
+    pub session_id: i32,
+    /// The ID of the teacher.
+    pub teacher_id: i32,
+    /// The full name of the teacher.
+    pub teacher_name: String,
+    /// The ID of the student the slot is booked for, if booked.
+    pub student_id: Option<i32>,
+    /// The start time of the time slot. In ISO 8601 format.
+    pub start: String,
+    /// The finish time of the time slot. In ISO 8601 format.
+    pub finish: String,
+    /// The ID of the location of the time slot, matching [`Location::id`].
+    pub location_id: Option<i32>,
+    /// The name of the location of the time slot.
+    pub location_name: Option<String>,
+    /// Indicates whether the time slot is booked.
+    pub booked: bool,
+}
+impl ConferenceSlot {
+    /// Finds the location of the time slot among `locations`, as returned by
+    /// [`get_all_locations`](crate::endpoints::locations::get_all_locations).
+    pub fn location<'a>(&self, locations: &'a [Location]) -> Option<&'a Location> {
+        let id = self.location_id?;
+        locations.iter().find(|location| location.id == id)
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::endpoints::reference::campuses::Campus;
 use crate::utils::consts::USER_AGENT;
 /// Retrieves upcoming events.
 ///
@@ -19,7 +20,7 @@ pub async fn get_upcoming_events(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -95,7 +96,7 @@ pub async fn get_past_events(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -170,6 +171,172 @@ pub async fn get_all_events(
     all_events.extend(upcoming_events);
     Ok(all_events)
 }
+/// Retrieves the consent form of an event, with the fields that must be filled in to give consent.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `event_id` - The ID of the event.
+/// * `student_id` - The ID of the student attending the event.
+/// * `school_id` - The ID of the school.
+pub async fn get_consent_form(
+    cookies: String,
+    event_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<ConsentForm, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ActionCentre.svc/GetConsentForm",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&EventStudentReq {
+            event_id,
+            student_id,
+        })
+        .send()
+        .await?;
+    let res = req.json::<GetConsentFormRes>().await?;
+    let mut fields: Vec<ConsentFormField> = Vec::new();
+    for field in res.d.fields {
+        let e = ConsentFormField {
+            id: field.id,
+            name: field.name,
+            description: field.description,
+            field_type: field.field_type,
+            required: field.required,
+            options: field.options.unwrap_or_default(),
+        };
+        fields.push(e);
+    }
+    Ok(ConsentForm {
+        id: res.d.id,
+        name: res.d.name,
+        content: res.d.content,
+        fields,
+    })
+}
+/// Gives consent for a student to attend an event.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `event_id` - The ID of the event.
+/// * `student_id` - The ID of the student attending the event.
+/// * `answers` - The answers to the fields of the consent form, if the event has one.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::events::{give_consent, ConsentFormAnswer};
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let answers = vec![ConsentFormAnswer { field_id: 1, value: "Peanuts".to_string() }];
+/// let result = give_consent("***".to_string(), 0, 0, &answers, "***").await?;
+/// println!("{}", result.attendee_status);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn give_consent(
+    cookies: String,
+    event_id: i32,
+    student_id: i32,
+    answers: &[ConsentFormAnswer],
+    school_id: &str,
+) -> Result<ConsentResult, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ActionCentre.svc/GiveConsent",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let mut field_values: Vec<ConsentFieldValueReq> = Vec::new();
+    for answer in answers {
+        let e = ConsentFieldValueReq {
+            field_id: answer.field_id,
+            value: answer.value.clone(),
+        };
+        field_values.push(e);
+    }
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GiveConsentReq {
+            event_id,
+            student_id,
+            field_values,
+        })
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<ConsentResultRes>().await?;
+    Ok(ConsentResult {
+        event_id: res.d.event_id,
+        student_id: res.d.student_id,
+        attendee_status: res.d.attendee_status,
+    })
+}
+/// Declines an event on behalf of a student. Only possible for events that allow declining.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `event_id` - The ID of the event.
+/// * `student_id` - The ID of the student invited to the event.
+/// * `school_id` - The ID of the school.
+pub async fn decline_event(
+    cookies: String,
+    event_id: i32,
+    student_id: i32,
+    school_id: &str,
+) -> Result<ConsentResult, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ActionCentre.svc/DeclineEvent",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&EventStudentReq {
+            event_id,
+            student_id,
+        })
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<ConsentResultRes>().await?;
+    Ok(ConsentResult {
+        event_id: res.d.event_id,
+        student_id: res.d.student_id,
+        attendee_status: res.d.attendee_status,
+    })
+}
 #[derive(Serialize, Deserialize, Debug)]
 struct GetUpcomingEventsRes {
     d: Vec<ActionCentreEvent>,
@@ -307,6 +474,119 @@ pub struct Session {
     /// The start time of the session.
     pub start: String,
 }
+#[derive(Serialize, Deserialize, Debug)]
+struct EventStudentReq {
+    #[serde(rename = "eventId")]
+    event_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetConsentFormRes {
+    d: ConsentFormRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConsentFormRes {
+    id: i32,
+    name: String,
+    content: String,
+    fields: Vec<ConsentFormFieldRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConsentFormFieldRes {
+    id: i32,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "type")]
+    field_type: i32,
+    required: bool,
+    options: Option<Vec<String>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GiveConsentReq {
+    #[serde(rename = "eventId")]
+    event_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+    #[serde(rename = "fieldValues")]
+    field_values: Vec<ConsentFieldValueReq>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConsentFieldValueReq {
+    #[serde(rename = "fieldId")]
+    field_id: i32,
+    value: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConsentResultRes {
+    d: ConsentResultData,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ConsentResultData {
+    #[serde(rename = "eventId")]
+    event_id: i32,
+    #[serde(rename = "studentId")]
+    student_id: i32,
+    #[serde(rename = "attendeeStatus")]
+    attendee_status: i32,
+}
+/// Represents the consent form of an event.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConsentForm {
+    /// The ID of the consent form.
+    pub id: i32,
+    /// The name of the consent form.
+    pub name: String,
+    /// The content of the consent form shown to parents.
+    pub content: String,
+    /// The fields to fill in when giving consent.
+    pub fields: Vec<ConsentFormField>,
+}
+/// Represents a field of a consent form.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConsentFormField {
+    /// The ID of the field.
+    pub id: i32,
+    /// The name of the field.
+    pub name: String,
+    /// The description of the field.
+    pub description: Option<String>,
+    /// The type of the field.
+    pub field_type: i32,
+    /// Indicates whether the field must be answered to give consent.
+    pub required: bool,
+    /// The allowed values of the field, for fields with a fixed set of options.
+    pub options: Vec<String>,
+}
+/// Represents the answer to a field of a consent form.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConsentFormAnswer {
+    /// The ID of the field, matching [`ConsentFormField::id`].
+    pub field_id: i32,
+    /// The value of the answer.
+    pub value: String,
+}
+/// Represents the outcome of giving consent for or declining an event.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ConsentResult {
+    /// The ID of the event.
+    pub event_id: i32,
+    /// The ID of the student.
+    pub student_id: i32,
+    /// The updated status of the attendee, as in [`Event::attendee_status`].
+    pub attendee_status: i32,
+}
+impl Session {
+    /// Finds the campus the session takes place at among `campuses`, as returned by
+    /// [`get_all_campuses`](crate::endpoints::reference::campuses::get_all_campuses).
+    ///
+    /// Sessions only carry the campus name, so the campus is matched by name.
+    pub fn campus<'a>(&self, campuses: &'a [Campus]) -> Option<&'a Campus> {
+        campuses
+            .iter()
+            .find(|campus| campus.name.eq_ignore_ascii_case(&self.campus_name))
+    }
+}
 // enum AttendeeStatus {
 //     ATTENDING,
 //     NA,
@@ -0,0 +1,600 @@
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use bytes::Bytes;
+use futures_util::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::news::{AssetRes, Attachment};
+use crate::endpoints::reference::academic_groups::AcademicGroup;
+use crate::utils::consts::USER_AGENT;
+use crate::utils::upload::{UploadError, UploadFile};
+
+/// Retrieves the learning tasks (assessments) of a student.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the student whose learning tasks are to be retrieved.
+/// * `academic_group` - The academic period to restrict the learning tasks to, as returned by
+///   [`get_all_academic_groups`](crate::endpoints::reference::academic_groups::get_all_academic_groups),
+///   or `None` for the current one.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::learning_tasks::get_learning_tasks_by_user;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let tasks = get_learning_tasks_by_user("***".to_string(), 0, None, "***").await?;
+/// for task in tasks {
+///     println!("{}: {:?} ({:?})", task.name, task.due_date, task.status);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_learning_tasks_by_user(
+    cookies: String,
+    user_id: i32,
+    academic_group: Option<&AcademicGroup>,
+    school_id: &str,
+) -> Result<Vec<LearningTask>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/LearningTasks.svc/GetAllLearningTasksByUserId",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let client = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?;
+    let limit = 50;
+    let mut page = 1;
+    let mut tasks: Vec<LearningTask> = Vec::new();
+    loop {
+        let body = GetAllLearningTasksByUserIdReq {
+            user_id,
+            academic_group_id: academic_group.map(|group| group.id).unwrap_or(-1),
+            page,
+            start: (page - 1) * limit,
+            limit,
+        };
+        let req = client.post(&url).json(&body).send().await?;
+        let res = req.json::<GetAllLearningTasksByUserIdRes>().await?;
+        let received = res.d.data.len();
+        for task in res.d.data {
+            tasks.push(learning_task_for_student(task, user_id));
+        }
+        if received == 0 || tasks.len() as i32 >= res.d.total {
+            break;
+        }
+        page += 1;
+    }
+    Ok(tasks)
+}
+fn learning_task_for_student(task: LearningTaskRes, user_id: i32) -> LearningTask {
+    let mut attachments: Vec<Attachment> = Vec::new();
+    for attachment in task.attachments.unwrap_or_default() {
+        attachments.push(attachment.into());
+    }
+    let student = task
+        .students
+        .unwrap_or_default()
+        .into_iter()
+        .find(|student| student.user_id == user_id);
+    let mut status = SubmissionStatus::NotSubmitted;
+    let mut feedback = None;
+    let mut results: Vec<LearningTaskResult> = Vec::new();
+    let mut submissions: Vec<Submission> = Vec::new();
+    if let Some(student) = student {
+        status = SubmissionStatus::from(student.submission_status);
+        feedback = student.comment;
+        for result in student.results.unwrap_or_default() {
+            let e = LearningTaskResult {
+                criterion: result.name,
+                result: result.result,
+                out_of: result.out_of,
+            };
+            results.push(e);
+        }
+        for submission in student.submissions.unwrap_or_default() {
+            let e = Submission {
+                id: submission.id,
+                file_name: submission.file_name,
+                submitted_at: submission.timestamp,
+                attachment: submission.asset.map(|asset| asset.into()),
+            };
+            submissions.push(e);
+        }
+    }
+    LearningTask {
+        id: task.id,
+        name: task.name,
+        description: task.description,
+        activity_id: task.activity_id,
+        activity_name: task.activity_name,
+        subject_name: task.subject_name,
+        academic_group_id: task.academic_group_id,
+        due_date: task.due_date_timestamp,
+        status,
+        results,
+        feedback,
+        submissions,
+        attachments,
+    }
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAllLearningTasksByUserIdReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "academicGroupId")]
+    academic_group_id: i32,
+    page: i32,
+    start: i32,
+    limit: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAllLearningTasksByUserIdRes {
+    d: LearningTasksPageRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LearningTasksPageRes {
+    data: Vec<LearningTaskRes>,
+    total: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LearningTaskRes {
+    id: i32,
+    name: String,
+    description: Option<String>,
+    #[serde(rename = "activityId")]
+    activity_id: i32,
+    #[serde(rename = "activityName")]
+    activity_name: String,
+    #[serde(rename = "subjectName")]
+    subject_name: Option<String>,
+    #[serde(rename = "academicGroupId")]
+    academic_group_id: Option<i32>,
+    #[serde(rename = "dueDateTimestamp")]
+    due_date_timestamp: Option<String>,
+    attachments: Option<Vec<AssetRes>>,
+    students: Option<Vec<LearningTaskStudentRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LearningTaskStudentRes {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "submissionStatus")]
+    submission_status: i32,
+    comment: Option<String>,
+    results: Option<Vec<LearningTaskResultRes>>,
+    submissions: Option<Vec<LearningTaskSubmissionRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LearningTaskResultRes {
+    name: String,
+    result: Option<String>,
+    #[serde(rename = "outOf")]
+    out_of: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct LearningTaskSubmissionRes {
+    id: i32,
+    #[serde(rename = "fileName")]
+    file_name: String,
+    timestamp: String,
+    asset: Option<AssetRes>,
+}
+/// Represents a learning task (assessment) from the perspective of one student.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LearningTask {
+    /// The ID of the learning task.
+    pub id: i32,
+    /// The name of the learning task.
+    pub name: String,
+    /// The description of the learning task.
+    pub description: Option<String>,
+    /// The ID of the activity (class) the learning task belongs to.
+    pub activity_id: i32,
+    /// The name of the activity (class) the learning task belongs to.
+    pub activity_name: String,
+    /// The name of the subject the learning task belongs to.
+    pub subject_name: Option<String>,
+    /// The ID of the academic period the learning task belongs to.
+    pub academic_group_id: Option<i32>,
+    /// The due date of the learning task. In ISO 8601 format.
+    pub due_date: Option<String>,
+    /// The submission status of the student.
+    pub status: SubmissionStatus,
+    /// The results (grades) given to the student.
+    pub results: Vec<LearningTaskResult>,
+    /// The teacher's feedback to the student, if any.
+    pub feedback: Option<String>,
+    /// The files the student has submitted.
+    pub submissions: Vec<Submission>,
+    /// The list of attachments provided with the learning task.
+    pub attachments: Vec<Attachment>,
+}
+/// The submission status of a student for a [`LearningTask`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionStatus {
+    /// Nothing has been submitted yet.
+    NotSubmitted,
+    /// Submitted on or before the due date.
+    OnTime,
+    /// Submitted after the due date.
+    Late,
+    /// A status not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for SubmissionStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => SubmissionStatus::NotSubmitted,
+            2 => SubmissionStatus::OnTime,
+            3 => SubmissionStatus::Late,
+            other => SubmissionStatus::Other(other),
+        }
+    }
+}
+/// Represents a result (grade) for one criterion of a learning task.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct LearningTaskResult {
+    /// The name of the criterion.
+    pub criterion: String,
+    /// The result given, e.g. `A` or `17`. `None` if not yet graded.
+    pub result: Option<String>,
+    /// The maximum result, for numeric criteria.
+    pub out_of: Option<String>,
+}
+/// Represents a file submitted by a student for a learning task.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Submission {
+    /// The ID of the submission.
+    pub id: i32,
+    /// The name of the submitted file.
+    pub file_name: String,
+    /// The time the file was submitted. In ISO 8601 format.
+    pub submitted_at: String,
+    /// The uploaded file, if it can be downloaded.
+    pub attachment: Option<Attachment>,
+}
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+/// Submits one or more files for a learning task.
+///
+/// Files are validated against
+/// [`MAX_UPLOAD_FILE_SIZE`](crate::utils::upload::MAX_UPLOAD_FILE_SIZE) before anything
+/// is sent.
+/// `on_progress` is called with the number of bytes sent so far and the total number
+/// of bytes as the upload proceeds.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `learning_task_id` - ID of the learning task to submit to.
+/// * `user_id` - ID of the student submitting.
+/// * `files` - The files to submit.
+/// * `on_progress` - Called with `(bytes_sent, total_bytes)` during the upload.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::learning_tasks::{submit_learning_task, SubmissionError};
+/// # use compassapi::utils::upload::UploadFile;
+/// # async fn run() -> Result<(), SubmissionError> {
+/// let file = UploadFile::from_path("essay.pdf").await?;
+/// let receipt = submit_learning_task("***".to_string(), 0, 0, vec![file], |sent, total| {
+///     println!("{}/{}", sent, total);
+/// }, "***").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn submit_learning_task(
+    cookies: String,
+    learning_task_id: i32,
+    user_id: i32,
+    files: Vec<UploadFile>,
+    on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+    school_id: &str,
+) -> Result<SubmissionReceipt, SubmissionError> {
+    let base_url = format!("https://{}.compass.education", school_id);
+    upload_submission(
+        &base_url,
+        cookies,
+        learning_task_id,
+        user_id,
+        files,
+        on_progress,
+    )
+    .await
+}
+/// Submits one or more files for a learning task to the given base URL.
+///
+/// This is what [`submit_learning_task`] does after building the school's URL. It is
+/// crate-private because it sends the session cookies to whatever `base_url` it is
+/// given; the tests use it to point the upload at a local server.
+///
+/// # Arguments
+///
+/// * `base_url` - The base URL of the Compass tenant, e.g. `https://***.compass.education`.
+/// * `cookies` - Cookies for authentication.
+/// * `learning_task_id` - ID of the learning task to submit to.
+/// * `user_id` - ID of the student submitting.
+/// * `files` - The files to submit.
+/// * `on_progress` - Called with `(bytes_sent, total_bytes)` during the upload.
+pub(crate) async fn upload_submission(
+    base_url: &str,
+    cookies: String,
+    learning_task_id: i32,
+    user_id: i32,
+    files: Vec<UploadFile>,
+    on_progress: impl Fn(u64, u64) + Send + Sync + 'static,
+) -> Result<SubmissionReceipt, SubmissionError> {
+    if files.is_empty() {
+        return Err(SubmissionError::NoFiles);
+    }
+    let mut total: u64 = 0;
+    for file in &files {
+        total += file.checked_size()?;
+    }
+    let url = format!(
+        "{}/Services/LearningTasks.svc/SubmitLearningTaskFiles",
+        base_url.trim_end_matches('/')
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let on_progress = Arc::new(on_progress);
+    let sent = Arc::new(AtomicU64::new(0));
+    let mut form = reqwest::multipart::Form::new()
+        .text("learningTaskId", learning_task_id.to_string())
+        .text("userId", user_id.to_string());
+    for file in files {
+        let length = file.content.len() as u64;
+        let content = Bytes::from(file.content);
+        let chunks = (0..content.len())
+            .step_by(UPLOAD_CHUNK_SIZE)
+            .map(move |start| content.slice(start..content.len().min(start + UPLOAD_CHUNK_SIZE)));
+        let on_progress = on_progress.clone();
+        let sent = sent.clone();
+        let stream = futures_util::stream::iter(chunks).map(move |chunk| {
+            let so_far = sent.fetch_add(chunk.len() as u64, Ordering::SeqCst) + chunk.len() as u64;
+            on_progress(so_far, total);
+            Ok::<Bytes, std::io::Error>(chunk)
+        });
+        let mut part = reqwest::multipart::Part::stream_with_length(
+            reqwest::Body::wrap_stream(stream),
+            length,
+        )
+        .file_name(file.file_name);
+        if let Some(mime_type) = file.mime_type {
+            part = part.mime_str(&mime_type)?;
+        }
+        form = form.part("file", part);
+    }
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .multipart(form)
+        .send()
+        .await?
+        .error_for_status()?;
+    let res = req.json::<SubmitLearningTaskFilesRes>().await?;
+    let mut files: Vec<SubmittedFile> = Vec::new();
+    for file in res.d.files {
+        let e = SubmittedFile {
+            id: file.id,
+            file_name: file.file_name,
+            size: file.size,
+        };
+        files.push(e);
+    }
+    Ok(SubmissionReceipt {
+        learning_task_id: res.d.learning_task_id,
+        user_id: res.d.user_id,
+        submitted_at: res.d.timestamp,
+        files,
+    })
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SubmitLearningTaskFilesRes {
+    d: SubmissionReceiptRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SubmissionReceiptRes {
+    #[serde(rename = "learningTaskId")]
+    learning_task_id: i32,
+    #[serde(rename = "userId")]
+    user_id: i32,
+    timestamp: String,
+    files: Vec<SubmittedFileRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SubmittedFileRes {
+    id: i32,
+    #[serde(rename = "fileName")]
+    file_name: String,
+    size: u64,
+}
+/// Represents the receipt returned after a successful submission.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubmissionReceipt {
+    /// The ID of the learning task submitted to.
+    pub learning_task_id: i32,
+    /// The ID of the student who submitted.
+    pub user_id: i32,
+    /// The time the submission was received. In ISO 8601 format.
+    pub submitted_at: String,
+    /// The files that were received.
+    pub files: Vec<SubmittedFile>,
+}
+/// Represents a file received as part of a submission.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SubmittedFile {
+    /// The ID of the submitted file.
+    pub id: i32,
+    /// The name of the submitted file.
+    pub file_name: String,
+    /// The size of the submitted file, in bytes.
+    pub size: u64,
+}
+/// An error that occurred while submitting files for a learning task.
+#[derive(Debug)]
+pub enum SubmissionError {
+    /// No files were given.
+    NoFiles,
+    /// A file could not be read or is not accepted for upload.
+    File(UploadError),
+    /// The request failed or the server rejected the submission.
+    Request(reqwest::Error),
+}
+impl fmt::Display for SubmissionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmissionError::NoFiles => write!(f, "no files to submit"),
+            SubmissionError::File(e) => write!(f, "{}", e),
+            SubmissionError::Request(e) => write!(f, "submission failed: {}", e),
+        }
+    }
+}
+impl std::error::Error for SubmissionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SubmissionError::File(e) => Some(e),
+            SubmissionError::Request(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<UploadError> for SubmissionError {
+    fn from(e: UploadError) -> Self {
+        SubmissionError::File(e)
+    }
+}
+impl From<reqwest::Error> for SubmissionError {
+    fn from(e: reqwest::Error) -> Self {
+        SubmissionError::Request(e)
+    }
+}
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Accepts a single request, returns its raw body and answers with `response`.
+    async fn serve_once(listener: TcpListener, response: &'static str) -> Vec<u8> {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut received: Vec<u8> = Vec::new();
+        let mut buf = [0u8; 8192];
+        let header_end = loop {
+            let n = socket.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before headers were received");
+            received.extend_from_slice(&buf[..n]);
+            if let Some(i) = received.windows(4).position(|w| w == b"\r\n\r\n") {
+                break i + 4;
+            }
+        };
+        let head = String::from_utf8_lossy(&received[..header_end]).to_lowercase();
+        assert!(
+            head.starts_with("post /services/learningtasks.svc/submitlearningtaskfiles http/1.1")
+        );
+        assert!(head.contains("cookie: session=abc"));
+        let length: usize = head
+            .lines()
+            .find_map(|line| line.strip_prefix("content-length: "))
+            .expect("multipart body has a known length")
+            .trim()
+            .parse()
+            .unwrap();
+        while received.len() < header_end + length {
+            let n = socket.read(&mut buf).await.unwrap();
+            assert!(n > 0, "connection closed before body was received");
+            received.extend_from_slice(&buf[..n]);
+        }
+        let reply = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            response.len(),
+            response
+        );
+        socket.write_all(reply.as_bytes()).await.unwrap();
+        received[header_end..].to_vec()
+    }
+
+    fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+        haystack.windows(needle.len()).any(|w| w == needle)
+    }
+
+    #[tokio::test]
+    async fn upload_submission_sends_form_and_parses_receipt() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve_once(
+            listener,
+            r#"{"d":{"learningTaskId":42,"userId":7,"timestamp":"2024-05-08T10:30:00Z","files":[{"id":1,"fileName":"essay.txt","size":150000},{"id":2,"fileName":"notes.txt","size":5}]}}"#,
+        ));
+
+        let essay: Vec<u8> = (0..150_000u32).map(|i| (i % 251) as u8).collect();
+        let files = vec![
+            UploadFile {
+                file_name: "essay.txt".to_string(),
+                mime_type: Some("text/plain".to_string()),
+                content: essay.clone(),
+            },
+            UploadFile {
+                file_name: "notes.txt".to_string(),
+                mime_type: None,
+                content: b"notes".to_vec(),
+            },
+        ];
+        let progress: Arc<Mutex<Vec<(u64, u64)>>> = Arc::new(Mutex::new(Vec::new()));
+        let recorded = progress.clone();
+        let receipt = upload_submission(
+            &base_url,
+            "session=abc".to_string(),
+            42,
+            7,
+            files,
+            move |sent, total| recorded.lock().unwrap().push((sent, total)),
+        )
+        .await
+        .unwrap();
+        let body = server.await.unwrap();
+
+        assert!(contains(&body, b"name=\"learningTaskId\"\r\n\r\n42\r\n"));
+        assert!(contains(&body, b"name=\"userId\"\r\n\r\n7\r\n"));
+        assert!(contains(&body, b"name=\"file\"; filename=\"essay.txt\""));
+        assert!(contains(&body, b"name=\"file\"; filename=\"notes.txt\""));
+        assert!(contains(&body, &essay));
+        assert!(contains(&body, b"\r\n\r\nnotes\r\n"));
+
+        let total = 150_005;
+        let progress = progress.lock().unwrap();
+        assert!(progress.len() > 2);
+        assert!(progress.iter().all(|&(_, t)| t == total));
+        assert!(progress.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(progress.last(), Some(&(total, total)));
+
+        assert_eq!(receipt.learning_task_id, 42);
+        assert_eq!(receipt.user_id, 7);
+        assert_eq!(receipt.submitted_at, "2024-05-08T10:30:00Z");
+        assert_eq!(receipt.files.len(), 2);
+        assert_eq!(receipt.files[0].file_name, "essay.txt");
+        assert_eq!(receipt.files[0].size, 150_000);
+    }
+}
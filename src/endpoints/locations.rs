@@ -18,7 +18,7 @@ pub async fn get_all_locations(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -0,0 +1,377 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::news::{AssetRes, Attachment};
+use crate::utils::consts::USER_AGENT;
+use crate::utils::html::html_to_text;
+
+/// Retrieves a single page of the logged-in user's message inbox, newest first.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `page` - The page to retrieve, starting at 1.
+/// * `limit` - The maximum number of threads per page, at least 1.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::messages::{get_inbox, get_message_thread};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let inbox = get_inbox("***".to_string(), 1, 25, "***").await?;
+/// for summary in inbox.threads.iter().filter(|t| t.unread) {
+///     let thread = get_message_thread("***".to_string(), summary.id, "***").await?;
+///     println!("{}: {} messages", thread.subject, thread.messages.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_inbox(
+    cookies: String,
+    page: i32,
+    limit: i32,
+    school_id: &str,
+) -> Result<InboxPage, InboxError> {
+    if page < 1 || limit < 1 {
+        return Err(InboxError::InvalidPage { page, limit });
+    }
+    let url = format!(
+        "https://{}.compass.education/Services/Messages.svc/GetInbox",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetInboxReq {
+        page,
+        start: (page - 1) * limit,
+        limit,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetInboxRes>().await?;
+    let mut threads: Vec<MessageThreadSummary> = Vec::new();
+    for thread in res.d.data {
+        let e = MessageThreadSummary {
+            id: thread.id,
+            subject: thread.subject,
+            preview: thread.preview,
+            last_sender_name: thread.last_sender_name,
+            last_message_at: thread.last_message_timestamp,
+            unread: thread.unread,
+            has_attachments: thread.has_attachments,
+        };
+        threads.push(e);
+    }
+    Ok(InboxPage {
+        threads,
+        total: res.d.total,
+    })
+}
+/// Retrieves a message thread with all of its messages and participants.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `thread_id` - The ID of the message thread.
+/// * `school_id` - The ID of the school.
+pub async fn get_message_thread(
+    cookies: String,
+    thread_id: i32,
+    school_id: &str,
+) -> Result<MessageThread, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Messages.svc/GetThread",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&ThreadReq { thread_id })
+        .send()
+        .await?;
+    let res = req.json::<GetThreadRes>().await?;
+    let mut participants: Vec<MessageParticipant> = Vec::new();
+    for participant in res.d.participants {
+        let e = MessageParticipant {
+            user_id: participant.user_id,
+            name: participant.name,
+        };
+        participants.push(e);
+    }
+    let mut messages: Vec<Message> = Vec::new();
+    for message in res.d.messages {
+        let mut attachments: Vec<Attachment> = Vec::new();
+        for attachment in message.attachments.unwrap_or_default() {
+            attachments.push(attachment.into());
+        }
+        let e = Message {
+            id: message.id,
+            sender_id: message.sender_id,
+            sender_name: message.sender_name,
+            sent_at: message.sent_timestamp,
+            body_text: html_to_text(&message.body),
+            body_html: message.body,
+            attachments,
+        };
+        messages.push(e);
+    }
+    Ok(MessageThread {
+        id: res.d.id,
+        subject: res.d.subject,
+        participants,
+        messages,
+    })
+}
+/// Retrieves the number of unread message threads of the logged-in user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_unread_count(cookies: String, school_id: &str) -> Result<i32, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Messages.svc/GetUnreadCount",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json("{}")
+        .send()
+        .await?;
+    let res = req.json::<GetUnreadCountRes>().await?;
+    Ok(res.d)
+}
+/// Marks every message in a thread as read.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `thread_id` - The ID of the message thread.
+/// * `school_id` - The ID of the school.
+pub async fn mark_thread_as_read(
+    cookies: String,
+    thread_id: i32,
+    school_id: &str,
+) -> Result<(), reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Messages.svc/MarkAsRead",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&ThreadReq { thread_id })
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetInboxReq {
+    page: i32,
+    start: i32,
+    limit: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetInboxRes {
+    d: InboxRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct InboxRes {
+    data: Vec<ThreadSummaryRes>,
+    total: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ThreadSummaryRes {
+    id: i32,
+    subject: String,
+    preview: Option<String>,
+    #[serde(rename = "lastSenderName")]
+    last_sender_name: String,
+    #[serde(rename = "lastMessageTimestamp")]
+    last_message_timestamp: String,
+    unread: bool,
+    #[serde(rename = "hasAttachments")]
+    has_attachments: bool,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ThreadReq {
+    #[serde(rename = "threadId")]
+    thread_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetThreadRes {
+    d: ThreadRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ThreadRes {
+    id: i32,
+    subject: String,
+    participants: Vec<ParticipantRes>,
+    messages: Vec<MessageRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ParticipantRes {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "n")]
+    name: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct MessageRes {
+    id: i32,
+    #[serde(rename = "senderId")]
+    sender_id: i32,
+    #[serde(rename = "senderName")]
+    sender_name: String,
+    #[serde(rename = "sentTimestamp")]
+    sent_timestamp: String,
+    body: String,
+    attachments: Option<Vec<AssetRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUnreadCountRes {
+    d: i32,
+}
+/// Represents a page of the message inbox.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct InboxPage {
+    /// The message threads on this page.
+    pub threads: Vec<MessageThreadSummary>,
+    /// The total number of message threads across all pages.
+    pub total: i32,
+}
+/// Represents a message thread as listed in the inbox.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MessageThreadSummary {
+    /// The ID of the message thread.
+    pub id: i32,
+    /// The subject of the message thread.
+    pub subject: String,
+    /// The start of the latest message, as plain text.
+    pub preview: Option<String>,
+    /// The name of the sender of the latest message.
+    pub last_sender_name: String,
+    /// The time of the latest message. In ISO 8601 format.
+    pub last_message_at: String,
+    /// Indicates whether the thread has unread messages.
+    pub unread: bool,
+    /// Indicates whether any message in the thread has attachments.
+    pub has_attachments: bool,
+}
+/// Represents a message thread.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MessageThread {
+    /// The ID of the message thread.
+    pub id: i32,
+    /// The subject of the message thread.
+    pub subject: String,
+    /// The users taking part in the thread.
+    pub participants: Vec<MessageParticipant>,
+    /// The messages of the thread, oldest first.
+    pub messages: Vec<Message>,
+}
+/// Represents a user taking part in a message thread.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MessageParticipant {
+    /// The ID of the user.
+    pub user_id: i32,
+    /// The full name of the user.
+    pub name: String,
+}
+/// Represents a message within a thread.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Message {
+    /// The ID of the message.
+    pub id: i32,
+    /// The ID of the sender.
+    pub sender_id: i32,
+    /// The full name of the sender.
+    pub sender_name: String,
+    /// The time the message was sent. In ISO 8601 format.
+    pub sent_at: String,
+    /// The body of the message, as HTML.
+    pub body_html: String,
+    /// The body of the message, rendered as plain text.
+    pub body_text: String,
+    /// The list of attachments associated with the message.
+    pub attachments: Vec<Attachment>,
+}
+/// An error that occurred while retrieving the message inbox.
+#[derive(Debug)]
+pub enum InboxError {
+    /// The requested page or page size is below 1.
+    InvalidPage {
+        /// The requested page.
+        page: i32,
+        /// The requested page size.
+        limit: i32,
+    },
+    /// The request failed.
+    Request(reqwest::Error),
+}
+impl fmt::Display for InboxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InboxError::InvalidPage { page, limit } => write!(
+                f,
+                "invalid page {} of size {}, both must be at least 1",
+                page, limit
+            ),
+            InboxError::Request(e) => write!(f, "request failed: {}", e),
+        }
+    }
+}
+impl std::error::Error for InboxError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InboxError::Request(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<reqwest::Error> for InboxError {
+    fn from(e: reqwest::Error) -> Self {
+        InboxError::Request(e)
+    }
+}
@@ -1,8 +1,16 @@
+pub mod alerts;
 pub mod attendance;
 pub mod auth;
 pub mod calendar;
+pub mod chronicle;
+pub mod conferences;
 pub mod events;
+pub mod learning_tasks;
 pub mod locations;
+pub mod messages;
 pub mod news;
-// pub mod tasks;
+pub mod reference;
+pub mod reports;
+pub mod subjects;
+pub mod tasks;
 pub mod user;
@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWrite;
 
 use crate::utils::consts::USER_AGENT;
+use crate::utils::download::{
+    reject_redirect, same_site_redirects, tenant_url, write_body_skipping, DownloadError,
+};
 /// Retrieves the news feed.
 ///
 /// # Arguments
@@ -19,7 +23,7 @@ pub async fn get_news_feed(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let req = reqwest::ClientBuilder::new()
         .cookie_store(true)
@@ -32,41 +36,56 @@ pub async fn get_news_feed(
     let res = req.json::<GetMyNewsFeedRes>().await?;
     let mut items: Vec<NewsItem> = Vec::new();
     for item in res.d {
-        let mut attachments: Vec<Attachment> = Vec::new();
-        for attachment in item.attachments {
-            let e = Attachment {
-                id: attachment.id,
-                file_type: attachment.file_type,
-                is_image: attachment.is_image,
-                name: attachment.name,
-                original_file_name: attachment.original_file_name,
-                ui_link: attachment.ui_link,
-                url: attachment.url,
-            };
-            attachments.push(e);
-        }
-        let content = match item.content2 {
-            Some(c) => item.content1 + &c,
-            None => item.content1.clone(),
-        };
-        let e = NewsItem {
-            id: item.news_item_id,
-            title: item.title,
-            created_at: item.post_date_time,
-            communication_type: item.communication_type,
-            content,
-            attachments,
-            created_by_admin: item.created_by_admin,
-            finish: item.finish,
-            priority: item.priority,
-            author_id: item.user_id,
-            author_image_url: item.user_image_url,
-            username: item.username,
-        };
-        items.push(e);
+        items.push(item.into());
     }
     Ok(items)
 }
+/// Retrieves a single news item, including items that are no longer in the news feed.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `news_item_id` - The ID of the news item.
+/// * `school_id` - The ID of the school.
+pub async fn get_news_item_by_id(
+    cookies: String,
+    news_item_id: &str,
+    school_id: &str,
+) -> Result<NewsItem, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/services/mobile.svc/GetNewsItemById",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetNewsItemByIdReq {
+        news_item_id: news_item_id.to_string(),
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetNewsItemByIdRes>().await?;
+    Ok(res.d.into())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetNewsItemByIdReq {
+    #[serde(rename = "newsItemId")]
+    news_item_id: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetNewsItemByIdRes {
+    d: NewsItemRes,
+}
 #[derive(Serialize, Deserialize, Debug)]
 struct GetMyNewsFeedRes {
     d: Vec<NewsItemRes>,
@@ -106,6 +125,36 @@ pub struct Attachment {
     /// The URL of the attachment, if available.
     pub url: Option<String>,
 }
+/// A file asset as returned in camelCase by most services, e.g. chronicle entries,
+/// learning tasks, lesson plans and messages.
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct AssetRes {
+    #[serde(rename = "assetId")]
+    id: i32,
+    #[serde(rename = "fileAssetType")]
+    file_type: i32,
+    #[serde(rename = "isImage")]
+    is_image: bool,
+    name: String,
+    #[serde(rename = "originalFileName")]
+    original_file_name: String,
+    #[serde(rename = "uiLink")]
+    ui_link: String,
+    url: Option<String>,
+}
+impl From<AssetRes> for Attachment {
+    fn from(asset: AssetRes) -> Self {
+        Attachment {
+            id: asset.id,
+            file_type: asset.file_type,
+            is_image: asset.is_image,
+            name: asset.name,
+            original_file_name: asset.original_file_name,
+            ui_link: asset.ui_link,
+            url: asset.url,
+        }
+    }
+}
 #[derive(Serialize, Deserialize, Debug)]
 struct NewsItemRes {
     #[serde(rename = "Attachments")]
@@ -135,6 +184,41 @@ struct NewsItemRes {
     #[serde(rename = "UserName")]
     username: String,
 }
+impl From<NewsItemRes> for NewsItem {
+    fn from(item: NewsItemRes) -> Self {
+        let mut attachments: Vec<Attachment> = Vec::new();
+        for attachment in item.attachments {
+            let e = Attachment {
+                id: attachment.id,
+                file_type: attachment.file_type,
+                is_image: attachment.is_image,
+                name: attachment.name,
+                original_file_name: attachment.original_file_name,
+                ui_link: attachment.ui_link,
+                url: attachment.url,
+            };
+            attachments.push(e);
+        }
+        let content = match item.content2 {
+            Some(c) => item.content1 + &c,
+            None => item.content1.clone(),
+        };
+        NewsItem {
+            id: item.news_item_id,
+            title: item.title,
+            created_at: item.post_date_time,
+            communication_type: item.communication_type,
+            content,
+            attachments,
+            created_by_admin: item.created_by_admin,
+            finish: item.finish,
+            priority: item.priority,
+            author_id: item.user_id,
+            author_image_url: item.user_image_url,
+            username: item.username,
+        }
+    }
+}
 /// Represents a news item.
 #[derive(Serialize, Deserialize, Debug)]
 pub struct NewsItem {
@@ -163,3 +247,347 @@ pub struct NewsItem {
     /// The username of the author.
     pub username: String,
 }
+/// Retrieves the upcoming items shown on the dashboard, such as due learning tasks,
+/// events, consent deadlines and calendar items.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_my_upcoming(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<UpcomingItem>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/NewsFeed.svc/GetMyUpcoming",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .send()
+        .await?;
+    let res = req.json::<GetMyUpcomingRes>().await?;
+    let mut items: Vec<UpcomingItem> = Vec::new();
+    for item in res.d {
+        let e = UpcomingItem {
+            id: item.id,
+            title: item.title,
+            description: item.description,
+            kind: UpcomingItemKind::from(item.item_type),
+            due: item.due_date_time,
+            link: item.ui_link,
+            target_id: item.target_id,
+            user_id: item.user_id,
+        };
+        items.push(e);
+    }
+    Ok(items)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetMyUpcomingRes {
+    d: Vec<UpcomingItemRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct UpcomingItemRes {
+    #[serde(rename = "Id")]
+    id: String,
+    #[serde(rename = "Title")]
+    title: String,
+    #[serde(rename = "Description")]
+    description: Option<String>,
+    #[serde(rename = "ItemType")]
+    item_type: i32,
+    #[serde(rename = "DueDateTime")]
+    due_date_time: String,
+    #[serde(rename = "UiLink")]
+    ui_link: Option<String>,
+    #[serde(rename = "TargetId")]
+    target_id: Option<i32>,
+    #[serde(rename = "UserId")]
+    user_id: i32,
+}
+/// Represents an item in the "Upcoming" dashboard panel.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UpcomingItem {
+    /// The ID of the upcoming item.
+    pub id: String,
+    /// The title of the upcoming item.
+    pub title: String,
+    /// The description of the upcoming item, if any.
+    pub description: Option<String>,
+    /// The kind of the upcoming item.
+    pub kind: UpcomingItemKind,
+    /// The time the item is due or starts. In ISO 8601 format.
+    pub due: String,
+    /// The relative link to the item in the Compass web UI.
+    pub link: Option<String>,
+    /// The ID of the item the link points to, e.g. a learning task or event ID.
+    pub target_id: Option<i32>,
+    /// The ID of the user the item belongs to.
+    pub user_id: i32,
+}
+/// The kind of an [`UpcomingItem`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpcomingItemKind {
+    /// A learning task that is due.
+    LearningTask,
+    /// An event from the Action Centre.
+    Event,
+    /// A consent or payment deadline for an event.
+    Consent,
+    /// A calendar item.
+    CalendarItem,
+    /// A kind not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for UpcomingItemKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => UpcomingItemKind::LearningTask,
+            2 => UpcomingItemKind::Event,
+            3 => UpcomingItemKind::Consent,
+            4 => UpcomingItemKind::CalendarItem,
+            other => UpcomingItemKind::Other(other),
+        }
+    }
+}
+/// Downloads an attachment, writing its content to `writer`.
+///
+/// The file is fetched from [`Attachment::url`], or [`Attachment::ui_link`] if there is
+/// none, with the session cookies. Only HTTPS URLs on the school's own Compass site are
+/// accepted; anything else fails with [`DownloadError::UntrustedUrl`] before a request
+/// is made.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `attachment` - The attachment to download.
+/// * `offset` - The number of bytes already downloaded, to resume a partial download.
+///   `0` downloads the whole file. If the file is exactly `offset` bytes long, nothing
+///   is written and the download counts as resumed; if the server reports any other
+///   size, it fails with [`DownloadError::OffsetMismatch`].
+/// * `writer` - Where the content is written, starting at `offset`.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::news::{download_attachment, get_news_feed};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let news = get_news_feed("***".to_string(), "***").await?;
+/// for attachment in news.iter().flat_map(|item| &item.attachments) {
+///     let mut file = tokio::fs::File::create(&attachment.original_file_name).await?;
+///     let download = download_attachment("***".to_string(), attachment, 0, &mut file, "***").await?;
+///     println!("{:?}: {} bytes", download.content_type, download.bytes_written);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_attachment<W>(
+    cookies: String,
+    attachment: &Attachment,
+    offset: u64,
+    writer: &mut W,
+    school_id: &str,
+) -> Result<AttachmentDownload, DownloadError>
+where
+    W: AsyncWrite + Unpin,
+{
+    let url = attachment_url(attachment, school_id)?;
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let mut req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .redirect(same_site_redirects(url.clone()))
+        .build()?
+        .get(url);
+    if offset > 0 {
+        req = req.header(reqwest::header::RANGE, format!("bytes={}-", offset));
+    }
+    let res = req.send().await?;
+    if offset > 0 && res.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        return nothing_past_offset(offset, content_range_total(&res));
+    }
+    let res = res.error_for_status()?;
+    reject_redirect(&res)?;
+    let resumed = res.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let content_type = res
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+    let total_size = if resumed {
+        content_range_total(&res)
+    } else {
+        res.content_length()
+    };
+    let skip = if resumed { 0 } else { offset };
+    let bytes_written = write_body_skipping(res, skip, writer).await?;
+    Ok(AttachmentDownload {
+        content_type,
+        total_size,
+        bytes_written,
+        resumed,
+    })
+}
+/// Resolves the download URL of an attachment, rejecting anything that is not HTTPS on
+/// the school's Compass site.
+fn attachment_url(attachment: &Attachment, school_id: &str) -> Result<reqwest::Url, DownloadError> {
+    tenant_url(
+        attachment.url.as_deref().unwrap_or(&attachment.ui_link),
+        school_id,
+    )
+}
+/// Handles a `416 Range Not Satisfiable` answer to a resumed download, which is only a
+/// finished download if the file is exactly `offset` bytes long.
+fn nothing_past_offset(
+    offset: u64,
+    size: Option<u64>,
+) -> Result<AttachmentDownload, DownloadError> {
+    if size != Some(offset) {
+        return Err(DownloadError::OffsetMismatch { offset, size });
+    }
+    Ok(AttachmentDownload {
+        content_type: None,
+        total_size: size,
+        bytes_written: 0,
+        resumed: true,
+    })
+}
+/// Reads the size of the whole file from a `Content-Range` header, e.g. `bytes 0-99/1000`
+/// or `bytes */1000`.
+fn content_range_total(res: &reqwest::Response) -> Option<u64> {
+    res.headers()
+        .get(reqwest::header::CONTENT_RANGE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit('/').next())
+        .and_then(|total| total.parse::<u64>().ok())
+}
+/// Represents the result of downloading an attachment.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct AttachmentDownload {
+    /// The MIME type of the file, as reported by the server.
+    pub content_type: Option<String>,
+    /// The size of the whole file in bytes, as reported by the server.
+    pub total_size: Option<u64>,
+    /// The number of bytes written to the writer by this call.
+    pub bytes_written: u64,
+    /// Indicates whether the server honoured the requested offset. If it did not, the
+    /// bytes before the offset were discarded so the writer still receives the rest of
+    /// the file.
+    pub resumed: bool,
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attachment(url: Option<&str>, ui_link: &str) -> Attachment {
+        Attachment {
+            id: 1,
+            file_type: 1,
+            is_image: false,
+            name: "Excursion note".to_string(),
+            original_file_name: "excursion.pdf".to_string(),
+            ui_link: ui_link.to_string(),
+            url: url.map(|url| url.to_string()),
+        }
+    }
+
+    fn resolve(url: &str) -> Result<String, String> {
+        attachment_url(&attachment(Some(url), "/x"), "myschool")
+            .map(|url| url.to_string())
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn resolves_relative_ui_link_on_school_site() {
+        let url = attachment_url(
+            &attachment(None, "/Services/FileAssets.svc/DownloadFile?id=12"),
+            "myschool",
+        )
+        .unwrap();
+        assert_eq!(
+            url.as_str(),
+            "https://myschool.compass.education/Services/FileAssets.svc/DownloadFile?id=12"
+        );
+    }
+
+    #[test]
+    fn prefers_url_over_ui_link() {
+        let url = attachment_url(
+            &attachment(Some("https://myschool.compass.education/a.pdf"), "/b.pdf"),
+            "myschool",
+        )
+        .unwrap();
+        assert_eq!(url.path(), "/a.pdf");
+    }
+
+    #[test]
+    fn accepts_school_id_with_uppercase_letters() {
+        let url = attachment_url(
+            &attachment(Some("https://myschool.compass.education/a.pdf"), "/x"),
+            "MySchool",
+        )
+        .unwrap();
+        assert_eq!(url.host_str(), Some("myschool.compass.education"));
+    }
+
+    #[test]
+    fn rejects_protocol_relative_url() {
+        assert!(resolve("//evil.com/x").is_err());
+    }
+
+    #[test]
+    fn rejects_plain_http() {
+        assert!(resolve("http://myschool.compass.education/a.pdf").is_err());
+    }
+
+    #[test]
+    fn rejects_lookalike_host() {
+        assert!(resolve("https://myschool.compass.education.evil.com/a.pdf").is_err());
+        assert!(resolve("https://otherschool.compass.education/a.pdf").is_err());
+    }
+
+    #[test]
+    fn finished_resume_writes_nothing() {
+        let download = nothing_past_offset(1000, Some(1000)).unwrap();
+        assert_eq!(download.bytes_written, 0);
+        assert_eq!(download.total_size, Some(1000));
+        assert!(download.resumed);
+    }
+
+    #[test]
+    fn resume_past_end_of_smaller_file_is_an_error() {
+        match nothing_past_offset(1000, Some(400)) {
+            Err(DownloadError::OffsetMismatch { offset, size }) => {
+                assert_eq!(offset, 1000);
+                assert_eq!(size, Some(400));
+            }
+            other => panic!("expected OffsetMismatch, got {:?}", other),
+        }
+        assert!(matches!(
+            nothing_past_offset(1000, None),
+            Err(DownloadError::OffsetMismatch { size: None, .. })
+        ));
+    }
+
+    #[test]
+    fn rejects_explicit_port() {
+        assert!(resolve("https://myschool.compass.education:8443/a.pdf").is_err());
+    }
+}
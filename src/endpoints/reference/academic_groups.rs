@@ -0,0 +1,146 @@
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::utils::consts::USER_AGENT;
+use crate::utils::dates::date_from_timestamp;
+/// Retrieves all academic groups (years, semesters and terms).
+///
+/// Period-scoped endpoints such as
+/// [`get_learning_tasks_by_user`](crate::endpoints::learning_tasks::get_learning_tasks_by_user)
+/// and [`get_classes_by_user`](crate::endpoints::subjects::get_classes_by_user) take one
+/// of these.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::reference::academic_groups::{current_academic_group, get_all_academic_groups, AcademicGroupKind};
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let groups = get_all_academic_groups("***".to_string(), "***").await?;
+/// if let Some(term) = current_academic_group(&groups, AcademicGroupKind::Term) {
+///     println!("{} ({} - {})", term.name, term.start, term.finish);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_all_academic_groups(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<AcademicGroup>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ReferenceDataCache.svc/GetAllAcademicGroups?page=1",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .get(url)
+        .send()
+        .await?;
+    let res = req.json::<GetAllAcademicGroupsResponse>().await?;
+    let mut groups: Vec<AcademicGroup> = Vec::new();
+    for group in res.d {
+        let e = AcademicGroup {
+            id: group.id,
+            name: group.name,
+            kind: AcademicGroupKind::from(group.group_type),
+            parent_id: group.parent_id,
+            start: group.start,
+            finish: group.finish,
+            is_current: group.is_relevant,
+        };
+        groups.push(e);
+    }
+    Ok(groups)
+}
+/// Finds the current academic group of a kind, e.g. the current term.
+pub fn current_academic_group(
+    groups: &[AcademicGroup],
+    kind: AcademicGroupKind,
+) -> Option<&AcademicGroup> {
+    groups
+        .iter()
+        .find(|group| group.kind == kind && group.is_current)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAllAcademicGroupsResponse {
+    d: Vec<AcademicGroupResponse>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct AcademicGroupResponse {
+    id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "type")]
+    group_type: i32,
+    #[serde(rename = "parentId")]
+    parent_id: Option<i32>,
+    #[serde(deserialize_with = "date_from_timestamp")]
+    start: NaiveDate,
+    #[serde(deserialize_with = "date_from_timestamp")]
+    finish: NaiveDate,
+    #[serde(rename = "isRelevant")]
+    is_relevant: bool,
+}
+/// Represents an academic group: a year, semester or term.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AcademicGroup {
+    /// The ID of the academic group.
+    pub id: i32,
+    /// The name of the academic group, e.g. `2024` or `Term 2`.
+    pub name: String,
+    /// The kind of the academic group.
+    pub kind: AcademicGroupKind,
+    /// The ID of the academic group this one is part of, e.g. the year of a term.
+    pub parent_id: Option<i32>,
+    /// The first day of the academic group.
+    pub start: NaiveDate,
+    /// The last day of the academic group.
+    pub finish: NaiveDate,
+    /// Indicates whether the academic group is the current one.
+    pub is_current: bool,
+}
+impl AcademicGroup {
+    /// The calendar year the academic group starts in, e.g. for
+    /// [`get_half_day_summary_grid_lines`](crate::endpoints::attendance::get_half_day_summary_grid_lines).
+    pub fn year(&self) -> i32 {
+        self.start.year()
+    }
+    /// Indicates whether a date falls within the academic group.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.finish
+    }
+}
+/// The kind of an [`AcademicGroup`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcademicGroupKind {
+    /// An academic year.
+    Year,
+    /// A semester.
+    Semester,
+    /// A term.
+    Term,
+    /// A kind not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for AcademicGroupKind {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => AcademicGroupKind::Year,
+            2 => AcademicGroupKind::Semester,
+            3 => AcademicGroupKind::Term,
+            other => AcademicGroupKind::Other(other),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::consts::USER_AGENT;
+use crate::utils::dates::date_from_timestamp;
+
+/// Retrieves the school's default calendar: terms, holidays, pupil-free days and the
+/// day-cycle number of each teaching day.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `start` - Start date of the range to retrieve (in a string format).
+/// * `end` - End date of the range to retrieve (in a string format).
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::reference::calendar_days::get_default_calendar_days;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let calendar = get_default_calendar_days("***".to_string(), "2024-01-01".to_string(), "2024-12-31".to_string(), "***").await?;
+/// let start = chrono::NaiveDate::from_ymd_opt(2024, 2, 1).unwrap();
+/// let end = chrono::NaiveDate::from_ymd_opt(2024, 3, 28).unwrap();
+/// println!("{}", calendar.teaching_days_between(start, end));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_default_calendar_days(
+    cookies: String,
+    start: String,
+    end: String,
+    school_id: &str,
+) -> Result<SchoolCalendar, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ReferenceData.svc/GetDefaultCalendarDays",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetDefaultCalendarDaysRequest {
+        start_date: start,
+        end_date: end,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetDefaultCalendarDaysResponse>().await?;
+    let mut terms: Vec<Term> = Vec::new();
+    for term in res.d.terms {
+        let e = Term {
+            id: term.id,
+            name: term.name,
+            start: term.start,
+            finish: term.finish,
+        };
+        terms.push(e);
+    }
+    let mut days: Vec<CalendarDay> = Vec::new();
+    for day in res.d.days {
+        let e = CalendarDay {
+            date: day.date,
+            kind: CalendarDayKind::from(day.day_type),
+            description: day.description,
+            term_id: day.term_id,
+            cycle_day: day.day_number,
+        };
+        days.push(e);
+    }
+    days.sort_by_key(|day| day.date);
+    Ok(SchoolCalendar { terms, days })
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetDefaultCalendarDaysRequest {
+    #[serde(rename = "startDate")]
+    start_date: String,
+    #[serde(rename = "endDate")]
+    end_date: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetDefaultCalendarDaysResponse {
+    d: DefaultCalendarRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct DefaultCalendarRes {
+    terms: Vec<TermRes>,
+    days: Vec<CalendarDayRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct TermRes {
+    id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(deserialize_with = "date_from_timestamp")]
+    start: NaiveDate,
+    #[serde(deserialize_with = "date_from_timestamp")]
+    finish: NaiveDate,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct CalendarDayRes {
+    #[serde(deserialize_with = "date_from_timestamp")]
+    date: NaiveDate,
+    #[serde(rename = "dayType")]
+    day_type: i32,
+    description: Option<String>,
+    #[serde(rename = "termId")]
+    term_id: Option<i32>,
+    #[serde(rename = "dayNumber")]
+    day_number: Option<i32>,
+}
+/// Represents the school's calendar of terms and days.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SchoolCalendar {
+    /// The terms within the requested range.
+    pub terms: Vec<Term>,
+    /// The days within the requested range, sorted by date.
+    pub days: Vec<CalendarDay>,
+}
+impl SchoolCalendar {
+    /// Returns the calendar entry for a date, if it is within the retrieved range.
+    pub fn day(&self, date: NaiveDate) -> Option<&CalendarDay> {
+        self.days
+            .binary_search_by_key(&date, |day| day.date)
+            .ok()
+            .map(|i| &self.days[i])
+    }
+    /// Returns the term a date falls in, if any.
+    pub fn term_for(&self, date: NaiveDate) -> Option<&Term> {
+        self.terms
+            .iter()
+            .find(|term| term.start <= date && date <= term.finish)
+    }
+    /// Indicates whether students are expected at school on a date.
+    ///
+    /// Dates outside the retrieved range are never teaching days.
+    pub fn is_teaching_day(&self, date: NaiveDate) -> bool {
+        self.day(date)
+            .map(|day| day.kind == CalendarDayKind::TeachingDay)
+            .unwrap_or(false)
+    }
+    /// Counts the teaching days from `start` to `end`, both inclusive.
+    pub fn teaching_days_between(&self, start: NaiveDate, end: NaiveDate) -> usize {
+        self.days
+            .iter()
+            .filter(|day| start <= day.date && day.date <= end)
+            .filter(|day| day.kind == CalendarDayKind::TeachingDay)
+            .count()
+    }
+}
+/// Represents a school term.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Term {
+    /// The ID of the term.
+    pub id: i32,
+    /// The name of the term, e.g. `Term 1`.
+    pub name: String,
+    /// The first day of the term.
+    pub start: NaiveDate,
+    /// The last day of the term.
+    pub finish: NaiveDate,
+}
+/// Represents a single day in the school calendar.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CalendarDay {
+    /// The date of the day.
+    pub date: NaiveDate,
+    /// The kind of the day.
+    pub kind: CalendarDayKind,
+    /// The description of the day, e.g. the name of a public holiday.
+    pub description: Option<String>,
+    /// The ID of the term the day falls in, if any.
+    pub term_id: Option<i32>,
+    /// The number of the day in the school's timetable cycle. Only set for teaching days.
+    pub cycle_day: Option<i32>,
+}
+/// The kind of a [`CalendarDay`].
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarDayKind {
+    /// A normal school day.
+    TeachingDay,
+    /// A weekend day.
+    Weekend,
+    /// A school or public holiday.
+    Holiday,
+    /// A pupil-free (curriculum) day, where staff attend but students do not.
+    PupilFreeDay,
+    /// A kind not known to this crate, with the raw value returned by Compass.
+    Other(i32),
+}
+impl From<i32> for CalendarDayKind {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => CalendarDayKind::TeachingDay,
+            1 => CalendarDayKind::Weekend,
+            2 => CalendarDayKind::Holiday,
+            3 => CalendarDayKind::PupilFreeDay,
+            other => CalendarDayKind::Other(other),
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 5, day).unwrap()
+    }
+
+    fn calendar(kinds: &[(u32, CalendarDayKind)]) -> SchoolCalendar {
+        let days = kinds
+            .iter()
+            .map(|&(day, kind)| CalendarDay {
+                date: date(day),
+                kind,
+                description: None,
+                term_id: Some(2),
+                cycle_day: None,
+            })
+            .collect();
+        SchoolCalendar {
+            terms: Vec::new(),
+            days,
+        }
+    }
+
+    // Monday 6 May to Sunday 12 May 2024, with a pupil-free Wednesday and a holiday Friday.
+    fn week() -> SchoolCalendar {
+        calendar(&[
+            (6, CalendarDayKind::TeachingDay),
+            (7, CalendarDayKind::TeachingDay),
+            (8, CalendarDayKind::PupilFreeDay),
+            (9, CalendarDayKind::TeachingDay),
+            (10, CalendarDayKind::Holiday),
+            (11, CalendarDayKind::Weekend),
+            (12, CalendarDayKind::Weekend),
+        ])
+    }
+
+    #[test]
+    fn is_teaching_day_only_for_teaching_kinds() {
+        let calendar = week();
+        assert!(calendar.is_teaching_day(date(6)));
+        assert!(calendar.is_teaching_day(date(9)));
+        assert!(!calendar.is_teaching_day(date(8)));
+        assert!(!calendar.is_teaching_day(date(10)));
+        assert!(!calendar.is_teaching_day(date(11)));
+    }
+
+    #[test]
+    fn is_teaching_day_outside_range() {
+        let calendar = week();
+        assert!(!calendar.is_teaching_day(date(5)));
+        assert!(!calendar.is_teaching_day(date(13)));
+    }
+
+    #[test]
+    fn other_kinds_are_not_teaching_days() {
+        let calendar = calendar(&[(6, CalendarDayKind::Other(9))]);
+        assert!(!calendar.is_teaching_day(date(6)));
+        assert_eq!(calendar.teaching_days_between(date(6), date(6)), 0);
+    }
+
+    #[test]
+    fn teaching_days_between_includes_both_bounds() {
+        let calendar = week();
+        assert_eq!(calendar.teaching_days_between(date(6), date(12)), 3);
+        assert_eq!(calendar.teaching_days_between(date(6), date(7)), 2);
+        assert_eq!(calendar.teaching_days_between(date(9), date(9)), 1);
+    }
+
+    #[test]
+    fn teaching_days_between_ignores_days_outside_range() {
+        let calendar = week();
+        assert_eq!(calendar.teaching_days_between(date(1), date(31)), 3);
+        assert_eq!(calendar.teaching_days_between(date(13), date(20)), 0);
+    }
+
+    #[test]
+    fn teaching_days_between_reversed_range_is_empty() {
+        let calendar = week();
+        assert_eq!(calendar.teaching_days_between(date(12), date(6)), 0);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::utils::consts::USER_AGENT;
+use serde::{Deserialize, Serialize};
+/// Retrieves all campuses.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_all_campuses(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<Campus>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ReferenceDataCache.svc/GetAllCampuses?page=1",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .get(url)
+        .send()
+        .await?;
+    let res = req.json::<GetAllCampusesResponse>().await?;
+    let mut campuses: Vec<Campus> = Vec::new();
+    for campus in res.d {
+        let e = Campus {
+            id: campus.id,
+            archived: campus.archived,
+            name: campus.name,
+            address: campus.address,
+            timezone: campus.time_zone,
+        };
+        campuses.push(e);
+    }
+    Ok(campuses)
+}
+/// Finds a campus by ID.
+pub fn find_campus(campuses: &[Campus], id: i32) -> Option<&Campus> {
+    campuses.iter().find(|campus| campus.id == id)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAllCampusesResponse {
+    d: Vec<CampusResponse>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct CampusResponse {
+    id: i32,
+    archived: bool,
+    #[serde(rename = "n")]
+    name: String,
+    address: Option<String>,
+    #[serde(rename = "timeZone")]
+    time_zone: Option<String>,
+}
+/// Represents a campus.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Campus {
+    /// The ID of the campus.
+    pub id: i32,
+    /// Indicates whether the campus is archived.
+    pub archived: bool,
+    /// The name of the campus.
+    pub name: String,
+    /// The address of the campus, as free text.
+    pub address: Option<String>,
+    /// The time zone of the campus, e.g. `Australia/Melbourne`, if provided.
+    pub timezone: Option<String>,
+}
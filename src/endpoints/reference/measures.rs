@@ -0,0 +1,103 @@
+use crate::utils::consts::USER_AGENT;
+use serde::{Deserialize, Serialize};
+/// Retrieves all measures.
+///
+/// Measures are referenced by ID from chronicle and reporting data.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `school_id` - The ID of the school.
+pub async fn get_all_measures(
+    cookies: String,
+    school_id: &str,
+) -> Result<Vec<Measure>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/ReferenceDataCache.svc/GetAllMeasures?page=1",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .get(url)
+        .send()
+        .await?;
+    let res = req.json::<GetAllMeasuresResponse>().await?;
+    let mut measures: Vec<Measure> = Vec::new();
+    for measure in res.d {
+        let mut scale: Vec<MeasureScaleValue> = Vec::new();
+        for value in measure.scale_values.unwrap_or_default() {
+            let e = MeasureScaleValue {
+                value: value.value,
+                label: value.label,
+            };
+            scale.push(e);
+        }
+        let e = Measure {
+            id: measure.id,
+            archived: measure.archived,
+            name: measure.name,
+            category_id: measure.category_id,
+            category: measure.category_name,
+            scale,
+        };
+        measures.push(e);
+    }
+    Ok(measures)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetAllMeasuresResponse {
+    d: Vec<MeasureResponse>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct MeasureResponse {
+    id: i32,
+    archived: bool,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "categoryId")]
+    category_id: Option<i32>,
+    #[serde(rename = "categoryName")]
+    category_name: Option<String>,
+    #[serde(rename = "scaleValues")]
+    scale_values: Option<Vec<MeasureScaleValueResponse>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct MeasureScaleValueResponse {
+    #[serde(rename = "v")]
+    value: i32,
+    #[serde(rename = "n")]
+    label: String,
+}
+/// Represents a measure.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Measure {
+    /// The ID of the measure.
+    pub id: i32,
+    /// Indicates whether the measure is archived.
+    pub archived: bool,
+    /// The name of the measure.
+    pub name: String,
+    /// The ID of the category the measure belongs to.
+    pub category_id: Option<i32>,
+    /// The name of the category the measure belongs to.
+    pub category: Option<String>,
+    /// The values on the scale of the measure, from lowest to highest.
+    pub scale: Vec<MeasureScaleValue>,
+}
+/// Represents a value on the scale of a measure.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MeasureScaleValue {
+    /// The numeric value.
+    pub value: i32,
+    /// The label of the value.
+    pub label: String,
+}
@@ -0,0 +1,4 @@
+pub mod academic_groups;
+pub mod calendar_days;
+pub mod campuses;
+pub mod measures;
\ No newline at end of file
@@ -0,0 +1,155 @@
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWrite;
+
+use crate::utils::consts::USER_AGENT;
+use crate::utils::download::{
+    reject_redirect, same_site_redirects, tenant_url, write_body, DownloadError,
+};
+
+/// Retrieves the semester/progress reports published for a student.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the student whose reports are to be retrieved.
+/// * `school_id` - The ID of the school.
+pub async fn get_reports_for_student(
+    cookies: String,
+    user_id: i32,
+    school_id: &str,
+) -> Result<Vec<Report>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Reports.svc/GetReportsForStudent",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetReportsForStudentReq { user_id })
+        .send()
+        .await?;
+    let res = req.json::<GetReportsForStudentRes>().await?;
+    let mut reports: Vec<Report> = Vec::new();
+    for report in res.d {
+        let e = Report {
+            id: report.id,
+            user_id: report.user_id,
+            cycle_id: report.cycle_id,
+            cycle_name: report.cycle_name,
+            year: report.year,
+            published_at: report.published_date,
+            file_name: report.file_name,
+            download_path: report.download_url,
+        };
+        reports.push(e);
+    }
+    Ok(reports)
+}
+/// Streams the PDF of a report to `writer`, returning the number of bytes written.
+///
+/// The report is only requested from the school's own Compass site over HTTPS; any
+/// other download URL fails with [`DownloadError::UntrustedUrl`].
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `report` - The report to download.
+/// * `writer` - Where to write the PDF to, e.g. a [`tokio::fs::File`].
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::reports::{download_report, get_reports_for_student};
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let reports = get_reports_for_student("***".to_string(), 0, "***").await?;
+/// for report in reports {
+///     let mut file = tokio::fs::File::create(&report.file_name).await?;
+///     download_report("***".to_string(), &report, &mut file, "***").await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_report<W>(
+    cookies: String,
+    report: &Report,
+    writer: &mut W,
+    school_id: &str,
+) -> Result<u64, DownloadError>
+where
+    W: AsyncWrite + Unpin,
+{
+    let url = tenant_url(&report.download_path, school_id)?;
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .redirect(same_site_redirects(url.clone()))
+        .build()?
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?;
+    reject_redirect(&req)?;
+    write_body(req, writer).await
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetReportsForStudentReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetReportsForStudentRes {
+    d: Vec<ReportRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ReportRes {
+    id: i32,
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "cycleId")]
+    cycle_id: i32,
+    #[serde(rename = "cycleName")]
+    cycle_name: String,
+    year: i32,
+    #[serde(rename = "publishedDate")]
+    published_date: Option<String>,
+    #[serde(rename = "fileName")]
+    file_name: String,
+    #[serde(rename = "downloadUrl")]
+    download_url: String,
+}
+/// Represents a published report for a student.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Report {
+    /// The ID of the report.
+    pub id: i32,
+    /// The ID of the student the report is for.
+    pub user_id: i32,
+    /// The ID of the report cycle.
+    pub cycle_id: i32,
+    /// The name of the report cycle, e.g. `Semester 1`.
+    pub cycle_name: String,
+    /// The year of the report cycle.
+    pub year: i32,
+    /// The time the report was published. In ISO 8601 format.
+    pub published_at: Option<String>,
+    /// The file name of the report PDF.
+    pub file_name: String,
+    /// The path of the report PDF, relative to the school's Compass URL.
+    pub download_path: String,
+}
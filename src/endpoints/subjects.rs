@@ -0,0 +1,260 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::reference::academic_groups::AcademicGroup;
+use crate::endpoints::user::summary::{UserSummary, UserSummaryRes};
+use crate::utils::consts::USER_AGENT;
+
+/// Retrieves the classes (activities) a user is enrolled in or teaches.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the user whose classes are to be retrieved.
+/// * `academic_group` - The academic period to restrict the classes to, as returned by
+///   [`get_all_academic_groups`](crate::endpoints::reference::academic_groups::get_all_academic_groups),
+///   or `None` for the current one.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::subjects::get_classes_by_user;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let classes = get_classes_by_user("***".to_string(), 0, None, "***").await?;
+/// for class in classes {
+///     println!("{} - {}", class.subject_code, class.subject_name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_classes_by_user(
+    cookies: String,
+    user_id: i32,
+    academic_group: Option<&AcademicGroup>,
+    school_id: &str,
+) -> Result<Vec<Class>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Subjects.svc/GetStandardClassesOfUserInAcademicGroup",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let client = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?;
+    let limit = 500;
+    let mut page = 1;
+    let mut classes: Vec<Class> = Vec::new();
+    loop {
+        let body = GetStandardClassesRequest {
+            user_id,
+            academic_group_id: academic_group.map(|group| group.id).unwrap_or(-1),
+            page,
+            start: (page - 1) * limit,
+            limit,
+        };
+        let req = client.post(&url).json(&body).send().await?;
+        let res = req.json::<GetStandardClassesResponse>().await?;
+        let received = res.d.data.len();
+        for class in res.d.data {
+            let mut teachers: Vec<ClassTeacher> = Vec::new();
+            for manager in class.managers.unwrap_or_default() {
+                let t = ClassTeacher {
+                    user_id: manager.id,
+                    name: manager.name,
+                    import_id: manager.import_id,
+                };
+                teachers.push(t)
+            }
+            let e = Class {
+                activity_id: class.id,
+                name: class.name,
+                import_id: class.import_identifier,
+                subject_id: class.subject_id,
+                subject_code: class.subject_import_identifier,
+                subject_name: class.subject_long_name,
+                teachers,
+                default_location_id: class.default_location_id,
+                default_location_name: class.default_location_name,
+                academic_group_id: class.academic_group_id,
+                year_level: class.year_level,
+            };
+            classes.push(e)
+        }
+        if received == 0 || classes.len() as i32 >= res.d.total {
+            break;
+        }
+        page += 1;
+    }
+    Ok(classes)
+}
+/// Retrieves the students enrolled in a class (activity).
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `activity_id` - ID of the activity, as in [`Class::activity_id`].
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::subjects::get_class_roster;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let roster = get_class_roster("***".to_string(), 0, "***").await?;
+/// for student in roster {
+///     println!("{}, {} ({})", student.user.last_name, student.user.first_name, student.form_group);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_class_roster(
+    cookies: String,
+    activity_id: i32,
+    school_id: &str,
+) -> Result<Vec<RosterStudent>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/Activity.svc/GetEnrolmentsByActivityId",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetEnrolmentsRequest { activity_id })
+        .send()
+        .await?;
+    let res = req.json::<GetEnrolmentsResponse>().await?;
+    let mut students: Vec<RosterStudent> = Vec::new();
+    for student in res.d {
+        let e = RosterStudent {
+            user: student.summary.into(),
+            form_group: student.form_group,
+        };
+        students.push(e)
+    }
+    Ok(students)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetStandardClassesRequest {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "academicGroupId")]
+    academic_group_id: i32,
+    page: i32,
+    start: i32,
+    limit: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetStandardClassesResponse {
+    d: StandardClassesRes,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct StandardClassesRes {
+    data: Vec<StandardClassRes>,
+    total: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct StandardClassRes {
+    id: i32,
+    name: String,
+    #[serde(rename = "importIdentifier")]
+    import_identifier: String,
+    #[serde(rename = "subjectId")]
+    subject_id: i32,
+    #[serde(rename = "subjectImportIdentifier")]
+    subject_import_identifier: String,
+    #[serde(rename = "subjectLongName")]
+    subject_long_name: String,
+    managers: Option<Vec<ClassManagerRes>>,
+    #[serde(rename = "defaultLocationId")]
+    default_location_id: Option<i32>,
+    #[serde(rename = "defaultLocationName")]
+    default_location_name: Option<String>,
+    #[serde(rename = "academicGroupId")]
+    academic_group_id: i32,
+    #[serde(rename = "yearLevel")]
+    year_level: Option<String>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct ClassManagerRes {
+    id: i32,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "ii")]
+    import_id: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetEnrolmentsRequest {
+    #[serde(rename = "activityId")]
+    activity_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetEnrolmentsResponse {
+    d: Vec<EnrolledStudentRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct EnrolledStudentRes {
+    #[serde(flatten)]
+    summary: UserSummaryRes,
+    #[serde(rename = "formGroup")]
+    form_group: String,
+}
+/// Represents a class (activity) a user is enrolled in or teaches.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Class {
+    /// ID of the activity, as in [`Event::activity_id`](crate::endpoints::calendar::events::Event::activity_id).
+    pub activity_id: i32,
+    /// Name of the class.
+    pub name: String,
+    /// Import identifier of the class, as in
+    /// [`Event::activity_import_identifier`](crate::endpoints::calendar::events::Event::activity_import_identifier).
+    pub import_id: String,
+    /// ID of the subject the class belongs to.
+    pub subject_id: i32,
+    /// Code (import identifier) of the subject, e.g. `09ENG`.
+    pub subject_code: String,
+    /// Full name of the subject, e.g. `Year 9 English`.
+    pub subject_name: String,
+    /// Teachers of the class.
+    pub teachers: Vec<ClassTeacher>,
+    /// Optional ID of the room the class is usually held in.
+    pub default_location_id: Option<i32>,
+    /// Optional name of the room the class is usually held in.
+    pub default_location_name: Option<String>,
+    /// ID of the academic period the class runs in.
+    pub academic_group_id: i32,
+    /// Optional year level of the class, e.g. `Year 9`.
+    pub year_level: Option<String>,
+}
+/// Represents a teacher of a class.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ClassTeacher {
+    /// ID of the teacher.
+    pub user_id: i32,
+    /// Full name of the teacher.
+    pub name: String,
+    /// Import identifier of the teacher.
+    pub import_id: String,
+}
+/// Represents a student enrolled in a class.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct RosterStudent {
+    /// The fields shared with other users, such as the ID and names of the student.
+    #[serde(flatten)]
+    pub user: UserSummary,
+    /// The form group of the student, e.g. `9B`.
+    pub form_group: String,
+}
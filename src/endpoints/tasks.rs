@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::consts::USER_AGENT;
+/// Retrieves the personal task items of a user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the user whose task items are to be retrieved.
+/// * `school_id` - The ID of the school.
+pub async fn get_task_items(
+    cookies: String,
+    user_id: i32,
+    school_id: &str,
+) -> Result<Vec<TaskItem>, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/TaskService.svc/GetTaskItems",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&GetTaskItemsReq { user_id })
+        .send()
+        .await?;
+    let res = req.json::<GetTaskItemsRes>().await?;
+    let mut items: Vec<TaskItem> = Vec::new();
+    for item in res.d {
+        let e = TaskItem {
+            id: Some(item.id),
+            user_id: item.user_id,
+            title: item.task_name,
+            due_date: item.due_date_timestamp,
+            completed: item.status,
+            activity_id: item.activity_id,
+        };
+        items.push(e);
+    }
+    Ok(items)
+}
+/// Creates or updates a personal task item.
+///
+/// A task item without an `id` is created, otherwise the existing task item is updated.
+/// Returns the ID of the saved task item.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `item` - The task item to save.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::tasks::{save_task_item, TaskItem};
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let item = TaskItem {
+///     id: None,
+///     user_id: 0,
+///     title: "Finish history essay".to_string(),
+///     due_date: Some("2024-05-08T00:00:00Z".to_string()),
+///     completed: false,
+///     activity_id: None,
+/// };
+/// let id = save_task_item("***".to_string(), &item, "***").await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn save_task_item(
+    cookies: String,
+    item: &TaskItem,
+    school_id: &str,
+) -> Result<i32, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/TaskService.svc/SaveTaskItem",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = SaveTaskItemReq {
+        item: TaskItemReq {
+            id: item.id.unwrap_or(0),
+            user_id: item.user_id,
+            task_name: item.title.clone(),
+            due_date_timestamp: item.due_date.clone(),
+            status: item.completed,
+            activity_id: item.activity_id,
+        },
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<SaveTaskItemRes>().await?;
+    Ok(res.d)
+}
+/// Deletes a personal task item.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `id` - The ID of the task item to delete.
+/// * `school_id` - The ID of the school.
+pub async fn delete_task_item(
+    cookies: String,
+    id: i32,
+    school_id: &str,
+) -> Result<(), reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/TaskService.svc/DeleteTaskItem",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&DeleteTaskItemReq { id })
+        .send()
+        .await?
+        .error_for_status()?;
+    Ok(())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetTaskItemsReq {
+    #[serde(rename = "userId")]
+    user_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetTaskItemsRes {
+    d: Vec<TaskItemRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct TaskItemRes {
+    id: i32,
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "taskName")]
+    task_name: String,
+    #[serde(rename = "dueDateTimestamp")]
+    due_date_timestamp: Option<String>,
+    status: bool,
+    #[serde(rename = "activityId")]
+    activity_id: Option<i32>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SaveTaskItemReq {
+    item: TaskItemReq,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct TaskItemReq {
+    id: i32,
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "taskName")]
+    task_name: String,
+    #[serde(rename = "dueDateTimestamp")]
+    due_date_timestamp: Option<String>,
+    status: bool,
+    #[serde(rename = "activityId")]
+    activity_id: Option<i32>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct SaveTaskItemRes {
+    d: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct DeleteTaskItemReq {
+    id: i32,
+}
+/// Represents a personal task item.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TaskItem {
+    /// The ID of the task item. `None` for task items that have not been saved yet.
+    pub id: Option<i32>,
+    /// The ID of the user the task item belongs to.
+    pub user_id: i32,
+    /// The title of the task item.
+    pub title: String,
+    /// The due date of the task item. In ISO 8601 format.
+    pub due_date: Option<String>,
+    /// Indicates whether the task item is completed.
+    pub completed: bool,
+    /// The ID of the activity (class) the task item is linked to, if any.
+    pub activity_id: Option<i32>,
+}
@@ -0,0 +1,266 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::reference::campuses::{find_campus, Campus};
+use crate::utils::consts::USER_AGENT;
+
+/// Retrieves the profile of the logged-in user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `user_id` - ID of the logged-in user.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::user::details::get_user_details;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let me = get_user_details("***".to_string(), 0, "***").await?;
+/// println!("{}", me.full_name);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_user_details(
+    cookies: String,
+    user_id: i32,
+    school_id: &str,
+) -> Result<UserDetails, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/services/mobile.svc/GetUserDetails",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetUserDetailsRequest { user_id };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetUserDetailsResponse>().await?;
+    Ok(res.d.into())
+}
+/// Retrieves the profile of any user visible to the logged-in user.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `target_user_id` - ID of the user whose profile is to be retrieved.
+/// * `school_id` - The ID of the school.
+pub async fn get_user_details_by_user_id(
+    cookies: String,
+    target_user_id: i32,
+    school_id: &str,
+) -> Result<UserDetails, reqwest::Error> {
+    let url = format!(
+        "https://{}.compass.education/Services/User.svc/GetUserDetailsBlobByUserId",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetUserDetailsBlobRequest {
+        target_user_id,
+        id: target_user_id,
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetUserDetailsResponse>().await?;
+    Ok(res.d.into())
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUserDetailsRequest {
+    #[serde(rename = "userId")]
+    user_id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUserDetailsBlobRequest {
+    #[serde(rename = "targetUserId")]
+    target_user_id: i32,
+    id: i32,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetUserDetailsResponse {
+    d: UserDetailsBlob,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct UserDetailsBlob {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "userFirstName")]
+    first_name: String,
+    #[serde(rename = "userLastName")]
+    last_name: String,
+    #[serde(rename = "userFullName")]
+    full_name: String,
+    #[serde(rename = "userPreferredName")]
+    preferred_name: Option<String>,
+    #[serde(rename = "userPreferredLastName")]
+    preferred_last_name: Option<String>,
+    #[serde(rename = "userDisplayCode")]
+    display_code: Option<String>,
+    #[serde(rename = "userSussiID")]
+    import_id: Option<String>,
+    #[serde(rename = "userRole")]
+    base_role: i32,
+    #[serde(rename = "userYearLevel")]
+    year_level: Option<String>,
+    #[serde(rename = "userYearLevelId")]
+    year_level_id: Option<i32>,
+    #[serde(rename = "userFormGroup")]
+    form_group: Option<String>,
+    #[serde(rename = "userHouse")]
+    house: Option<String>,
+    #[serde(rename = "userPhotoPath")]
+    photo_path: Option<String>,
+    #[serde(rename = "userSquarePhotoPath")]
+    square_photo_path: Option<String>,
+    #[serde(rename = "userCampusId")]
+    campus_id: Option<i32>,
+    #[serde(rename = "userCampusName")]
+    campus_name: Option<String>,
+    #[serde(rename = "userEmail")]
+    email: Option<String>,
+    #[serde(rename = "userFamilyMembers")]
+    family_members: Option<Vec<FamilyMemberRes>>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct FamilyMemberRes {
+    #[serde(rename = "userId")]
+    user_id: i32,
+    #[serde(rename = "firstName")]
+    first_name: String,
+    #[serde(rename = "lastName")]
+    last_name: String,
+    #[serde(rename = "fullName")]
+    full_name: String,
+    #[serde(rename = "relationship")]
+    relationship: Option<String>,
+    #[serde(rename = "baseRole")]
+    base_role: i32,
+    #[serde(rename = "photoPath")]
+    photo_path: Option<String>,
+}
+impl From<UserDetailsBlob> for UserDetails {
+    fn from(blob: UserDetailsBlob) -> Self {
+        let mut family_members: Vec<FamilyMember> = Vec::new();
+        for member in blob.family_members.unwrap_or_default() {
+            let e = FamilyMember {
+                id: member.user_id,
+                first_name: member.first_name,
+                last_name: member.last_name,
+                full_name: member.full_name,
+                relationship: member.relationship,
+                base_role: member.base_role,
+                photo_path: member.photo_path,
+            };
+            family_members.push(e);
+        }
+        UserDetails {
+            id: blob.user_id,
+            first_name: blob.first_name,
+            last_name: blob.last_name,
+            full_name: blob.full_name,
+            preferred_name: blob.preferred_name,
+            preferred_last_name: blob.preferred_last_name,
+            display_code: blob.display_code,
+            import_id: blob.import_id,
+            base_role: blob.base_role,
+            year_level: blob.year_level,
+            year_level_id: blob.year_level_id,
+            form_group: blob.form_group,
+            house: blob.house,
+            photo_path: blob.photo_path,
+            square_photo_path: blob.square_photo_path,
+            campus_id: blob.campus_id,
+            campus_name: blob.campus_name,
+            email: blob.email,
+            family_members,
+        }
+    }
+}
+/// Represents the profile of a user.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct UserDetails {
+    /// The ID of the user.
+    pub id: i32,
+    /// The first name of the user.
+    pub first_name: String,
+    /// The last name of the user.
+    pub last_name: String,
+    /// The full name of the user.
+    pub full_name: String,
+    /// The preferred first name of the user, if set.
+    pub preferred_name: Option<String>,
+    /// The preferred last name of the user, if set.
+    pub preferred_last_name: Option<String>,
+    /// The display code of the user.
+    pub display_code: Option<String>,
+    /// The import identifier of the user.
+    pub import_id: Option<String>,
+    /// The base role of the user.
+    pub base_role: i32,
+    /// The year level of the user, e.g. `Year 9`. Only set for students.
+    pub year_level: Option<String>,
+    /// The ID of the year level of the user. Only set for students.
+    pub year_level_id: Option<i32>,
+    /// The form group of the user. Only set for students.
+    pub form_group: Option<String>,
+    /// The house of the user.
+    pub house: Option<String>,
+    /// The URL path to the photo of the user.
+    pub photo_path: Option<String>,
+    /// The URL path to the square (cropped) photo of the user.
+    pub square_photo_path: Option<String>,
+    /// The ID of the campus the user belongs to.
+    pub campus_id: Option<i32>,
+    /// The name of the campus the user belongs to.
+    pub campus_name: Option<String>,
+    /// The email address of the user.
+    pub email: Option<String>,
+    /// The family members linked to the user.
+    pub family_members: Vec<FamilyMember>,
+}
+impl UserDetails {
+    /// Finds the campus the user belongs to among `campuses`, as returned by
+    /// [`get_all_campuses`](crate::endpoints::reference::campuses::get_all_campuses).
+    pub fn campus<'a>(&self, campuses: &'a [Campus]) -> Option<&'a Campus> {
+        find_campus(campuses, self.campus_id?)
+    }
+}
+/// Represents a family member linked to a user.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FamilyMember {
+    /// The ID of the family member.
+    pub id: i32,
+    /// The first name of the family member.
+    pub first_name: String,
+    /// The last name of the family member.
+    pub last_name: String,
+    /// The full name of the family member.
+    pub full_name: String,
+    /// The relationship of the family member to the user, e.g. `Mother`.
+    pub relationship: Option<String>,
+    /// The base role of the family member.
+    pub base_role: i32,
+    /// The URL path to the photo of the family member.
+    pub photo_path: Option<String>,
+}
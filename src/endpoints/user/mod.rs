@@ -1 +1,7 @@
-pub mod staff;
\ No newline at end of file
+pub mod details;
+pub mod names;
+pub mod staff;
+pub mod summary;
+
+/// The ID of a Compass user.
+pub type UserId = i32;
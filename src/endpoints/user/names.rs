@@ -0,0 +1,173 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use super::UserId;
+use crate::utils::consts::USER_AGENT;
+
+/// Resolves the names of several users in a single request.
+///
+/// Ids that Compass does not know about (or that the logged-in user cannot see) are
+/// left out of the result.
+///
+/// # Arguments
+///
+/// * `cookies` - Cookies for authentication.
+/// * `ids` - IDs of the users whose names are to be retrieved.
+/// * `school_id` - The ID of the school.
+/// # Example
+///
+/// ```no_run
+/// # use compassapi::endpoints::user::names::get_names_by_id;
+/// # async fn run() -> Result<(), reqwest::Error> {
+/// let names = get_names_by_id("***".to_string(), &[4225, 4226], "***").await?;
+/// println!("{:?}", names.get(&4225));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_names_by_id(
+    cookies: String,
+    ids: &[UserId],
+    school_id: &str,
+) -> Result<HashMap<UserId, UserName>, reqwest::Error> {
+    if ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let url = format!(
+        "https://{}.compass.education/Services/User.svc/GetNamesById",
+        school_id
+    );
+    let mut headers = reqwest::header::HeaderMap::new();
+    let cookies_str: &str = cookies.as_str();
+    headers.insert(
+        "cookie",
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
+    );
+    let body = GetNamesByIdRequest {
+        user_ids: ids.to_vec(),
+    };
+    let req = reqwest::ClientBuilder::new()
+        .cookie_store(true)
+        .default_headers(headers)
+        .user_agent(USER_AGENT)
+        .build()?
+        .post(url)
+        .json(&body)
+        .send()
+        .await?;
+    let res = req.json::<GetNamesByIdResponse>().await?;
+    let mut names: HashMap<UserId, UserName> = HashMap::new();
+    for ele in res.d {
+        let name = UserName {
+            id: ele.id,
+            name: ele.name,
+            first_name: ele.first_name,
+            last_name: ele.last_name,
+            name_pref_first: ele.name_pref_first,
+            display_code: ele.display_code,
+        };
+        names.insert(name.id, name);
+    }
+    Ok(names)
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetNamesByIdRequest {
+    #[serde(rename = "userIds")]
+    user_ids: Vec<UserId>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct GetNamesByIdResponse {
+    d: Vec<UserNameRes>,
+}
+#[derive(Serialize, Deserialize, Debug)]
+struct UserNameRes {
+    id: UserId,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "fn")]
+    first_name: String,
+    #[serde(rename = "ln")]
+    last_name: String,
+    #[serde(rename = "namePrefFirst")]
+    name_pref_first: Option<String>,
+    #[serde(rename = "displayCode")]
+    display_code: Option<String>,
+}
+/// Represents the name of a user.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserName {
+    /// The ID of the user.
+    pub id: UserId,
+    /// The full name of the user.
+    pub name: String,
+    /// The first name of the user.
+    pub first_name: String,
+    /// The last name of the user.
+    pub last_name: String,
+    /// The preferred first name of the user, if set.
+    pub name_pref_first: Option<String>,
+    /// The display code of the user.
+    pub display_code: Option<String>,
+}
+/// An in-memory cache in front of [`get_names_by_id`].
+///
+/// Only ids that have not been resolved before are sent to Compass, so repeatedly
+/// rendering the same calendar or news feed costs at most one request. Ids Compass
+/// returns no name for are remembered too, and are not requested again until
+/// [`NameCache::clear`] is called.
+#[derive(Debug, Default)]
+pub struct NameCache {
+    names: HashMap<UserId, UserName>,
+    unknown: HashSet<UserId>,
+}
+impl NameCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Returns the cached name of a user, without making a request.
+    pub fn get(&self, id: UserId) -> Option<&UserName> {
+        self.names.get(&id)
+    }
+    /// Resolves the names of several users, requesting only the ids missing from the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `cookies` - Cookies for authentication.
+    /// * `ids` - IDs of the users whose names are to be retrieved.
+    /// * `school_id` - The ID of the school.
+    pub async fn resolve(
+        &mut self,
+        cookies: String,
+        ids: &[UserId],
+        school_id: &str,
+    ) -> Result<HashMap<UserId, UserName>, reqwest::Error> {
+        let mut missing: Vec<UserId> = Vec::new();
+        for id in ids {
+            if !self.names.contains_key(id) && !self.unknown.contains(id) && !missing.contains(id) {
+                missing.push(*id);
+            }
+        }
+        if !missing.is_empty() {
+            let fetched = get_names_by_id(cookies, &missing, school_id).await?;
+            for id in missing {
+                if !fetched.contains_key(&id) {
+                    self.unknown.insert(id);
+                }
+            }
+            self.names.extend(fetched);
+        }
+        let mut names: HashMap<UserId, UserName> = HashMap::new();
+        for id in ids {
+            if let Some(name) = self.names.get(id) {
+                names.insert(*id, name.clone());
+            }
+        }
+        Ok(names)
+    }
+    /// Removes every cached name, including the ids Compass returned no name for.
+    pub fn clear(&mut self) {
+        self.names.clear();
+        self.unknown.clear();
+    }
+}
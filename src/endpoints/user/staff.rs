@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::endpoints::reference::campuses::{find_campus, Campus};
+use crate::endpoints::user::summary::UserSummary;
 use crate::utils::consts::USER_AGENT;
 
 /// Retrieves staff information.
@@ -24,7 +26,7 @@ pub async fn get_staff(
     let cookies_str: &str = cookies.as_str();
     headers.insert(
         "cookie",
-        reqwest::header::HeaderValue::from_str(&cookies_str).unwrap(),
+        reqwest::header::HeaderValue::from_str(cookies_str).unwrap(),
     );
     let body = GetAllStaffRequest {
         target_user_id: user_id,
@@ -105,13 +107,13 @@ pub struct StaffMember {
     pub base_role: i32,
     /// The ID of the campus the staff member belongs to.
     pub campus_id: Option<i32>,
-    ///
+    /// The `ce` field as returned by Compass (meaning undocumented).
     pub ce: String,
     /// The display code of the staff member.
     pub display_code: String,
     /// Indicates whether the staff member should not be contacted.
     pub do_not_contact: bool,
-    ///
+    /// The `f` field as returned by Compass (meaning undocumented).
     pub f: String,
     /// The first government code of the staff member.
     pub government_code_01: String,
@@ -128,6 +130,27 @@ pub struct StaffMember {
     /// The name_pref_last_id of the staff member.
     pub name_pref_last_id: String,
 }
+impl StaffMember {
+    /// Finds the campus the staff member belongs to among `campuses`, as returned by
+    /// [`get_all_campuses`](crate::endpoints::reference::campuses::get_all_campuses).
+    pub fn campus<'a>(&self, campuses: &'a [Campus]) -> Option<&'a Campus> {
+        find_campus(campuses, self.campus_id?)
+    }
+    /// Returns the fields the staff member shares with other users, in the same form as
+    /// [`RosterStudent::user`](crate::endpoints::subjects::RosterStudent::user).
+    pub fn summary(&self) -> UserSummary {
+        UserSummary {
+            id: self.id,
+            name: self.name.clone(),
+            import_id: self.import_id.clone(),
+            first_name: self.first_name.clone(),
+            last_name: self.last_name.clone(),
+            name_pref_first: Some(self.name_pref_first.clone()).filter(|name| !name.is_empty()),
+            picture: self.picture.clone(),
+            profile_picture: self.profile_picture.clone(),
+        }
+    }
+}
 #[derive(Serialize, Deserialize, Debug)]
 struct GetAllStaffRes {
     d: Vec<User>,
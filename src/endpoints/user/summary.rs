@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+use crate::endpoints::user::UserId;
+
+/// Represents the fields Compass returns for every kind of user, as embedded in
+/// [`RosterStudent`](crate::endpoints::subjects::RosterStudent) and returned by
+/// [`StaffMember::summary`](crate::endpoints::user::staff::StaffMember::summary).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UserSummary {
+    /// The ID of the user.
+    pub id: UserId,
+    /// The full name of the user.
+    pub name: String,
+    /// The import identifier of the user.
+    pub import_id: String,
+    /// The first name of the user.
+    pub first_name: String,
+    /// The last name of the user.
+    pub last_name: String,
+    /// The preferred first name of the user, if set.
+    pub name_pref_first: Option<String>,
+    /// The URL to the picture of the user.
+    pub picture: Option<String>,
+    /// The URL to the profile picture of the user.
+    pub profile_picture: String,
+}
+#[derive(Serialize, Deserialize, Debug)]
+pub(crate) struct UserSummaryRes {
+    id: UserId,
+    #[serde(rename = "n")]
+    name: String,
+    #[serde(rename = "ii")]
+    import_id: String,
+    #[serde(rename = "fn")]
+    first_name: String,
+    #[serde(rename = "ln")]
+    last_name: String,
+    #[serde(rename = "namePrefFirst")]
+    name_pref_first: Option<String>,
+    #[serde(rename = "p")]
+    picture: Option<String>,
+    #[serde(rename = "pv")]
+    profile_picture: String,
+}
+impl From<UserSummaryRes> for UserSummary {
+    fn from(user: UserSummaryRes) -> Self {
+        UserSummary {
+            id: user.id,
+            name: user.name,
+            import_id: user.import_id,
+            first_name: user.first_name,
+            last_name: user.last_name,
+            name_pref_first: user.name_pref_first,
+            picture: user.picture,
+            profile_picture: user.profile_picture,
+        }
+    }
+}
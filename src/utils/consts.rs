@@ -1 +1,3 @@
-pub const USER_AGENT:&str = "Compass API Rust/ V0";
\ No newline at end of file
+pub const USER_AGENT:&str = "Compass API Rust/ V0";
+/// The Compass host that serves the school directory, used before a school is known.
+pub const SCHOOL_DIRECTORY_URL: &str = "https://schools.compass.education";
@@ -0,0 +1,13 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a Compass timestamp such as `2024-05-08T00:00:00` (or a bare
+/// `2024-05-08`) into the date part only.
+pub(crate) fn date_from_timestamp<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    let date = s.get(..10).unwrap_or(&s);
+    NaiveDate::parse_from_str(date, "%Y-%m-%d").map_err(serde::de::Error::custom)
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// An error that occurred while downloading a file.
+#[derive(Debug)]
+pub enum DownloadError {
+    /// The downloaded bytes could not be written.
+    Io(std::io::Error),
+    /// The request failed or the server refused the download.
+    Request(reqwest::Error),
+    /// The file is not served over HTTPS from the school's Compass site, so it was not
+    /// requested with the session cookies. Contains the offending URL.
+    UntrustedUrl(String),
+    /// A download was resumed at `offset`, but the server reports the file is a
+    /// different size, e.g. because it was replaced. The partial file should be
+    /// discarded and downloaded again from the start.
+    OffsetMismatch {
+        /// The offset the download was resumed at.
+        offset: u64,
+        /// The size of the file reported by the server, if any.
+        size: Option<u64>,
+    },
+}
+impl fmt::Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DownloadError::Io(e) => write!(f, "could not write download: {}", e),
+            DownloadError::Request(e) => write!(f, "download failed: {}", e),
+            DownloadError::UntrustedUrl(url) => {
+                write!(f, "refusing to download from untrusted URL: {}", url)
+            }
+            DownloadError::OffsetMismatch {
+                offset,
+                size: Some(size),
+            } => write!(
+                f,
+                "cannot resume at byte {}, the file is {} bytes",
+                offset, size
+            ),
+            DownloadError::OffsetMismatch { offset, size: None } => {
+                write!(
+                    f,
+                    "cannot resume at byte {}, the file size is unknown",
+                    offset
+                )
+            }
+        }
+    }
+}
+impl std::error::Error for DownloadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DownloadError::Io(e) => Some(e),
+            DownloadError::Request(e) => Some(e),
+            DownloadError::UntrustedUrl(_) | DownloadError::OffsetMismatch { .. } => None,
+        }
+    }
+}
+impl From<std::io::Error> for DownloadError {
+    fn from(e: std::io::Error) -> Self {
+        DownloadError::Io(e)
+    }
+}
+impl From<reqwest::Error> for DownloadError {
+    fn from(e: reqwest::Error) -> Self {
+        DownloadError::Request(e)
+    }
+}
+/// Writes the body of a response to `writer` chunk by chunk, returning the number of
+/// bytes written.
+pub(crate) async fn write_body<W>(
+    res: reqwest::Response,
+    writer: &mut W,
+) -> Result<u64, DownloadError>
+where
+    W: AsyncWrite + Unpin,
+{
+    write_body_skipping(res, 0, writer).await
+}
+/// Like [`write_body`], but discards the first `skip` bytes of the body, e.g. when a
+/// server ignored a `Range` header and sent the whole file.
+pub(crate) async fn write_body_skipping<W>(
+    mut res: reqwest::Response,
+    mut skip: u64,
+    writer: &mut W,
+) -> Result<u64, DownloadError>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut written: u64 = 0;
+    while let Some(chunk) = res.chunk().await? {
+        let start = skip.min(chunk.len() as u64) as usize;
+        skip -= start as u64;
+        writer.write_all(&chunk[start..]).await?;
+        written += (chunk.len() - start) as u64;
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+/// Resolves a link returned by Compass, relative or absolute, against the school's site,
+/// rejecting anything that is not HTTPS on that site so the session cookies are never
+/// sent elsewhere.
+pub(crate) fn tenant_url(link: &str, school_id: &str) -> Result<reqwest::Url, DownloadError> {
+    let host = format!("{}.compass.education", school_id).to_ascii_lowercase();
+    let base = reqwest::Url::parse(&format!("https://{}/", host))
+        .map_err(|_| DownloadError::UntrustedUrl(link.to_string()))?;
+    let url = base
+        .join(link)
+        .map_err(|_| DownloadError::UntrustedUrl(link.to_string()))?;
+    if url.scheme() != "https" || url.host_str() != Some(host.as_str()) || url.port().is_some() {
+        return Err(DownloadError::UntrustedUrl(url.to_string()));
+    }
+    Ok(url)
+}
+/// A redirect policy that only follows redirects to the same HTTPS site as `origin`, so
+/// the session cookies are never sent elsewhere.
+pub(crate) fn same_site_redirects(origin: reqwest::Url) -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(move |attempt| {
+        let url = attempt.url();
+        if attempt.previous().len() >= 10 {
+            attempt.error("too many redirects")
+        } else if url.scheme() == "https"
+            && url.host_str() == origin.host_str()
+            && url.port().is_none()
+        {
+            attempt.follow()
+        } else {
+            attempt.stop()
+        }
+    })
+}
+/// Fails with [`DownloadError::UntrustedUrl`] if `res` is a redirect that
+/// [`same_site_redirects`] refused to follow.
+pub(crate) fn reject_redirect(res: &reqwest::Response) -> Result<(), DownloadError> {
+    if !res.status().is_redirection() {
+        return Ok(());
+    }
+    let location = res
+        .headers()
+        .get(reqwest::header::LOCATION)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+    let location = match res.url().join(location) {
+        Ok(url) => url.to_string(),
+        Err(_) => location.to_string(),
+    };
+    Err(DownloadError::UntrustedUrl(location))
+}
@@ -0,0 +1,181 @@
+/// Renders HTML content (as returned by Compass for lesson plans, news items, etc.)
+/// as plain text.
+///
+/// Block-level elements and `<br>` become line breaks, list items are prefixed with
+/// `- `, `<script>` and `<style>` contents and comments are removed, all other tags are
+/// dropped and named and numeric entities are decoded. A `<` that does not start a tag,
+/// as in `a < b`, is kept as text.
+pub fn html_to_text(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(open) = rest.find('<') {
+        text.push_str(&decode_entities(&rest[..open]));
+        let starts_tag = rest[open + 1..]
+            .chars()
+            .next()
+            .map(|c| c.is_ascii_alphabetic() || c == '/' || c == '!')
+            .unwrap_or(false);
+        if !starts_tag {
+            text.push('<');
+            rest = &rest[open + 1..];
+            continue;
+        }
+        if rest[open..].starts_with("<!--") {
+            rest = match rest[open..].find("-->") {
+                Some(end) => &rest[open + end + 3..],
+                None => "",
+            };
+            continue;
+        }
+        let Some(close) = rest[open..].find('>') else {
+            rest = &rest[open..];
+            break;
+        };
+        let tag = rest[open + 1..open + close].trim().to_ascii_lowercase();
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("");
+        rest = &rest[open + close + 1..];
+        if (name == "script" || name == "style") && !tag.starts_with('/') {
+            let end_tag = format!("</{}", name);
+            rest = match rest.to_ascii_lowercase().find(&end_tag) {
+                Some(end) => match rest[end..].find('>') {
+                    Some(end_close) => &rest[end + end_close + 1..],
+                    None => "",
+                },
+                None => "",
+            };
+            continue;
+        }
+        let is_block = matches!(
+            name,
+            "br" | "p"
+                | "div"
+                | "tr"
+                | "h1"
+                | "h2"
+                | "h3"
+                | "h4"
+                | "h5"
+                | "h6"
+                | "ul"
+                | "ol"
+                | "li"
+        );
+        if is_block && !text.is_empty() && !text.ends_with('\n') {
+            text.push('\n');
+        }
+        if name == "li" && !tag.starts_with('/') {
+            text.push_str("- ");
+        }
+    }
+    text.push_str(&decode_entities(rest));
+    let lines: Vec<&str> = text.lines().map(|line| line.trim()).collect();
+    lines.join("\n").trim().to_string()
+}
+/// Decodes the common named entities and all numeric (`&#8217;`, `&#x2019;`) entities.
+/// Anything that is not a known entity is left as is.
+fn decode_entities(s: &str) -> String {
+    let mut decoded = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        decoded.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|&end| end <= 10)
+            .and_then(|end| decode_entity(&rest[1..end + 1]).map(|c| (c, end + 2)));
+        match entity {
+            Some((c, len)) => {
+                decoded.push(c);
+                rest = &rest[len..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
+fn decode_entity(name: &str) -> Option<char> {
+    let code = if let Some(hex) = name.strip_prefix("#x").or_else(|| name.strip_prefix("#X")) {
+        u32::from_str_radix(hex, 16).ok()?
+    } else if let Some(dec) = name.strip_prefix('#') {
+        dec.parse::<u32>().ok()?
+    } else {
+        return match name {
+            "nbsp" => Some(' '),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "amp" => Some('&'),
+            _ => None,
+        };
+    };
+    match code {
+        0xA0 => Some(' '),
+        code => char::from_u32(code),
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_less_than_that_does_not_start_a_tag() {
+        assert_eq!(html_to_text("a < b and c > d"), "a < b and c > d");
+        assert_eq!(html_to_text("<p>1 <2</p>"), "1 <2");
+        assert_eq!(html_to_text("x <"), "x <");
+    }
+
+    #[test]
+    fn drops_script_and_style_contents() {
+        assert_eq!(html_to_text("<script>alert(1)</script>go"), "go");
+        assert_eq!(
+            html_to_text("<STYLE type=\"text/css\">p { color: red; }</STYLE><p>Hi</p>"),
+            "Hi"
+        );
+        assert_eq!(html_to_text("a<script>if (a < b) {}</script>b"), "ab");
+        assert_eq!(html_to_text("a<script>never closed"), "a");
+    }
+
+    #[test]
+    fn drops_comments() {
+        assert_eq!(html_to_text("a<!-- <b>hidden</b> -->b"), "ab");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            html_to_text("Tom&#8217;s &amp; Jo&#x2019;s"),
+            "Tom\u{2019}s & Jo\u{2019}s"
+        );
+        assert_eq!(
+            html_to_text("&lt;b&gt; &quot;x&quot; &#39;y&apos;"),
+            "<b> \"x\" 'y'"
+        );
+        assert_eq!(html_to_text("a&nbsp;b&#160;c"), "a b c");
+        assert_eq!(html_to_text("&amp;lt;"), "&lt;");
+    }
+
+    #[test]
+    fn leaves_unknown_entities() {
+        assert_eq!(
+            html_to_text("R&D; fish & chips &#xZZ; &#99999999;"),
+            "R&D; fish & chips &#xZZ; &#99999999;"
+        );
+    }
+
+    #[test]
+    fn renders_blocks_and_lists() {
+        assert_eq!(
+            html_to_text("<h1>Plan</h1><p>Read</p><ul><li>One</li><li>Two</li></ul>Done<br>!"),
+            "Plan\nRead\n- One\n- Two\nDone\n!"
+        );
+    }
+}
@@ -1 +1,5 @@
-pub mod consts;
\ No newline at end of file
+pub mod consts;
+pub mod dates;
+pub mod download;
+pub mod html;
+pub mod upload;
\ No newline at end of file
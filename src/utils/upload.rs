@@ -0,0 +1,111 @@
+use std::fmt;
+use std::path::Path;
+
+/// The largest file Compass accepts as an upload, in bytes.
+pub const MAX_UPLOAD_FILE_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Represents a file to be uploaded, e.g. as a learning task submission or a chronicle
+/// attachment.
+#[derive(Debug, Clone)]
+pub struct UploadFile {
+    /// The name of the file, as shown to other users.
+    pub file_name: String,
+    /// The MIME type of the file, e.g. `application/pdf`. Guessed by the server if `None`.
+    pub mime_type: Option<String>,
+    /// The content of the file.
+    pub content: Vec<u8>,
+}
+impl UploadFile {
+    /// Reads a file from disk, using its file name as the uploaded file name.
+    ///
+    /// Files larger than [`MAX_UPLOAD_FILE_SIZE`] are rejected without being read.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, UploadError> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let size = tokio::fs::metadata(path).await?.len();
+        if size > MAX_UPLOAD_FILE_SIZE {
+            return Err(UploadError::FileTooLarge {
+                file_name,
+                size,
+                limit: MAX_UPLOAD_FILE_SIZE,
+            });
+        }
+        let content = tokio::fs::read(path).await?;
+        Ok(UploadFile {
+            file_name,
+            mime_type: None,
+            content,
+        })
+    }
+    /// Checks that the file is neither empty nor larger than [`MAX_UPLOAD_FILE_SIZE`],
+    /// returning its size in bytes.
+    pub(crate) fn checked_size(&self) -> Result<u64, UploadError> {
+        let size = self.content.len() as u64;
+        if size == 0 {
+            return Err(UploadError::EmptyFile {
+                file_name: self.file_name.clone(),
+            });
+        }
+        if size > MAX_UPLOAD_FILE_SIZE {
+            return Err(UploadError::FileTooLarge {
+                file_name: self.file_name.clone(),
+                size,
+                limit: MAX_UPLOAD_FILE_SIZE,
+            });
+        }
+        Ok(size)
+    }
+}
+/// An error that occurred while preparing a file for upload.
+#[derive(Debug)]
+pub enum UploadError {
+    /// A file has no content.
+    EmptyFile {
+        /// The name of the empty file.
+        file_name: String,
+    },
+    /// A file exceeds [`MAX_UPLOAD_FILE_SIZE`].
+    FileTooLarge {
+        /// The name of the file.
+        file_name: String,
+        /// The size of the file, in bytes.
+        size: u64,
+        /// The largest accepted size, in bytes.
+        limit: u64,
+    },
+    /// A file could not be read from disk.
+    Io(std::io::Error),
+}
+impl fmt::Display for UploadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UploadError::EmptyFile { file_name } => write!(f, "{} is empty", file_name),
+            UploadError::FileTooLarge {
+                file_name,
+                size,
+                limit,
+            } => write!(
+                f,
+                "{} is {} bytes, larger than the {} byte limit",
+                file_name, size, limit
+            ),
+            UploadError::Io(e) => write!(f, "could not read file: {}", e),
+        }
+    }
+}
+impl std::error::Error for UploadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            UploadError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+impl From<std::io::Error> for UploadError {
+    fn from(e: std::io::Error) -> Self {
+        UploadError::Io(e)
+    }
+}